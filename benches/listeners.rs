@@ -0,0 +1,113 @@
+#![allow(non_snake_case)]
+
+//! Counts the event listeners registered by NUI components, and times the first render of a long `List`.
+//!
+//! The "before" case reproduces what `render_component!` emitted for a `Button` when every global event
+//! was forwarded as is: a listener for each of the 51 global events of that time, whether a handler was
+//! supplied or not.
+//! Every other case uses the current components, so "every handler" follows the events they accept now
+//! (form and media events only on the components that use them, plus `onmounted`).
+//!
+//! Run with `cargo bench --bench listeners`.
+
+use dioxus::core::Mutation;
+use dioxus::prelude::*;
+use dioxus_nui::{Button, InitNui, List, ListItem};
+use std::time::Instant;
+
+/// Renders a `button` with a listener for each of `$event`, like `render_component!` did when it forwarded every event.
+macro_rules! button_with_listeners {
+    ($cx:ident, $($event:ident),*) => {
+        $cx.render(rsx! { button { class: "nui-btn nui-btn--regular", $($event: |_| {},)* "Button" } })
+    };
+}
+
+/// Renders a `Button` with a handler for every global event.
+macro_rules! button_with_every_handler {
+    ($cx:ident, $($event:ident),*) => {
+        $cx.render(rsx! { Button { $($event: |_| {},)* "Button" } })
+    };
+}
+
+fn main() {
+    println!("{:<32} {:>9}", "component", "listeners");
+    report("Button (before)", ButtonBefore);
+    report("Button (no handlers)", ButtonWithoutHandlers);
+    report("Button (onclick)", ButtonWithOnclick);
+    report("Button (every handler)", ButtonWithEveryHandler);
+    report("ListItem (no handlers)", SingleListItem);
+    report("List of 1000 ListItems", LongList);
+
+    let start = Instant::now();
+    let mut dom = VirtualDom::new(LongList);
+    let _ = dom.rebuild();
+    println!("\nFirst render of 1000 ListItems: {:?}", start.elapsed());
+}
+
+/// Prints the number of listeners registered when `app` is first rendered.
+fn report(name: &str, app: fn(Scope) -> Element) {
+    let mut dom = VirtualDom::new(app);
+    let listeners = dom
+        .rebuild()
+        .edits
+        .iter()
+        .filter(|edit| matches!(edit, Mutation::NewEventListener { .. }))
+        .count();
+
+    println!("{name:<32} {listeners:>9}");
+}
+
+/// The 51 listeners every component used to register, in the order of the old event list.
+fn ButtonBefore(cx: Scope) -> Element {
+    button_with_listeners!(
+        cx, onabort, onblur, onchange, onclick, oncontextmenu, ondblclick, ondrag, ondragend, ondragenter,
+        ondragleave, ondragover, ondragstart, ondrop, ondurationchange, onemptied, onended, onerror, onfocus,
+        oninput, oninvalid, onkeydown, onkeypress, onkeyup, onload, onloadeddata, onloadedmetadata,
+        onloadstart, onmousedown, onmouseenter, onmouseleave, onmousemove, onmouseout, onmouseover,
+        onmouseup, onpause, onplay, onplaying, onprogress, onratechange, onreset, onscroll, onseeked,
+        onseeking, onselect, onstalled, onsubmit, onsuspend, ontimeupdate, ontoggle, onvolumechange,
+        onwaiting
+    )
+}
+
+fn ButtonWithoutHandlers(cx: Scope) -> Element {
+    render! {
+        InitNui {}
+        Button { "Button" }
+    }
+}
+
+fn ButtonWithOnclick(cx: Scope) -> Element {
+    render! {
+        InitNui {}
+        Button { onclick: |_| {}, "Button" }
+    }
+}
+
+fn ButtonWithEveryHandler(cx: Scope) -> Element {
+    button_with_every_handler!(
+        cx, onblur, onclick, oncontextmenu, ondblclick, ondrag, ondragend, ondragenter, ondragleave,
+        ondragover, ondragstart, ondrop, onfocus, onkeydown, onkeypress, onkeyup, onmousedown, onmouseenter,
+        onmouseleave, onmousemove, onmouseout, onmouseover, onmouseup, onscroll, ontoggle, onfocusin,
+        onfocusout, onpointerdown, onpointermove, onpointerup, onpointercancel, onpointerenter,
+        onpointerleave, onpointerover, onpointerout, ongotpointercapture, onlostpointercapture, ontouchstart,
+        ontouchmove, ontouchend, ontouchcancel, onwheel, oncopy, oncut, onpaste, onanimationstart,
+        onanimationiteration, onanimationend, ontransitionend, onmounted
+    )
+}
+
+fn SingleListItem(cx: Scope) -> Element {
+    render! {
+        InitNui {}
+        List { ListItem { title: "Title" } }
+    }
+}
+
+fn LongList(cx: Scope) -> Element {
+    render! {
+        InitNui {}
+        List {
+            (0..1000).map(|i| rsx! { ListItem { key: "{i}", title: "Item {i}" } })
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Implementation of the [`nui_component`](crate::nui_component) attribute.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse::Parser, punctuated::Punctuated, Error, Fields, Ident, ItemStruct, Result, Token};
+
+use crate::global::{self, Group};
+
+/// Adds the global attributes and events to the properties struct in `item`.
+///
+/// `args` are the extra groups of events accepted by the component (`form`, `media`).
+pub(crate) fn nui_component(args: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let groups = Punctuated::<Ident, Token![,]>::parse_terminated
+        .parse2(args)?
+        .into_iter()
+        .map(|group| {
+            Group::from_name(&group.to_string()).ok_or_else(|| {
+                Error::new(
+                    group.span(),
+                    format!("unknown group of events `{group}`, expected `form` or `media`"),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut item: ItemStruct = syn::parse2(item)?;
+
+    let lifetime = match item.generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "NUI components borrow their properties, add a lifetime (`struct MyProps<'a>`)",
+            ))
+        }
+    };
+
+    // Properties declared by the component take the place of the global ones with the same name
+    let own: Vec<String> = item
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| ident.unraw().to_string())
+        .collect();
+
+    match &mut item.fields {
+        Fields::Named(fields) => fields.named.extend(global::fields(&lifetime, &groups, &own)),
+        fields => {
+            return Err(Error::new_spanned(
+                fields,
+                "`nui_component` only supports structs with named fields",
+            ))
+        }
+    }
+
+    let globals = global::globals(&item, &lifetime, &groups, &own);
+
+    Ok(quote! {
+        #item
+        #globals
+    })
+}
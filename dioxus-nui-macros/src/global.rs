@@ -0,0 +1,406 @@
+//! Global attributes and events shared by every NUI component.
+//!
+//! Used by [`nui_component`](crate::nui_component) to add the properties to a struct,
+//! and by [`render_component!`](crate::render_component) to forward them to the element with `$GLOBALS`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_quote, Expr, Field, ItemStruct, Lifetime, LitStr};
+
+use Group::*;
+use Kind::*;
+
+/// A [Global Attribute](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes).
+pub(crate) struct Attribute {
+    /// Name of the property.
+    pub(crate) name: &'static str,
+    /// Name of the HTML attribute, `None` if it's the same as the property.
+    pub(crate) html: Option<&'static str>,
+    pub(crate) kind: Kind,
+    pub(crate) doc: &'static str,
+}
+
+/// Type of an attribute's property, which defines how it is rendered.
+#[derive(Clone, Copy)]
+pub(crate) enum Kind {
+    /// `&str`, forwarded as is.
+    Str,
+    /// A type implementing `ToStr` (like `bool`, rendered as `"true"` or `"false"`).
+    Typed(&'static str),
+    /// Boolean attribute, only present if `true`.
+    Flag,
+    /// `bool` rendered as `"yes"` or `"no"`.
+    YesNo,
+    /// `i32`.
+    Int,
+}
+
+impl Attribute {
+    const fn new(name: &'static str, kind: Kind, doc: &'static str) -> Self {
+        Self {
+            name,
+            html: None,
+            kind,
+            doc,
+        }
+    }
+
+    /// [ARIA](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes) attribute.
+    const fn aria(name: &'static str, html: &'static str, kind: Kind, doc: &'static str) -> Self {
+        Self {
+            name,
+            html: Some(html),
+            kind,
+            doc,
+        }
+    }
+
+    /// Name of the attribute in the rendered HTML.
+    pub(crate) fn html_name(&self) -> &'static str {
+        self.html.unwrap_or(self.name)
+    }
+}
+
+/// A global event, `event` is the type of the event in `dioxus::prelude`.
+pub(crate) struct Event {
+    pub(crate) name: &'static str,
+    pub(crate) event: &'static str,
+    pub(crate) group: Group,
+    pub(crate) doc: &'static str,
+}
+
+impl Event {
+    const fn new(name: &'static str, event: &'static str, group: Group, doc: &'static str) -> Self {
+        Self {
+            name,
+            event,
+            group,
+            doc,
+        }
+    }
+}
+
+/// Events are grouped by the elements they make sense on, so components only accept the relevant ones.
+#[derive(PartialEq, Clone, Copy)]
+pub(crate) enum Group {
+    /// Added to every component.
+    Common,
+    /// Events of form elements, added with `#[nui_component(form)]`.
+    Form,
+    /// Events of media elements, added with `#[nui_component(media)]`.
+    Media,
+}
+
+impl Group {
+    /// Parses the name used in `#[nui_component(...)]`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "form" => Some(Group::Form),
+            "media" => Some(Group::Media),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) const ATTRIBUTES: &[Attribute] = &[
+    Attribute::new("accesskey", Str, "Specifies a shortcut key to activate or focus an element."),
+    Attribute::new("autocapitalize", Typed("::dioxus_nui::AutoCapitalize"), "Controls whether and how text input is automatically capitalized as it is entered/edited by the user."),
+    Attribute::new("autofocus", Flag, "Indicates whether an element should have input focus when the page loads."),
+    Attribute::new("contenteditable", Typed("bool"), "Indicates whether the content of an element can be edited by the user."),
+    Attribute::new("dir", Typed("::dioxus_nui::Dir"), "Specifies the text direction for the content in an element."),
+    Attribute::new("draggable", Typed("bool"), "Specifies whether an element is draggable or not."),
+    Attribute::new("enterkeyhint", Typed("::dioxus_nui::EnterKeyHint"), "Provides a hint for the type of user action that is expected when an element receives focus."),
+    Attribute::new("hidden", Flag, "Indicates whether the element is relevant."),
+    Attribute::new("id", Str, "Defines a unique identifier for an element."),
+    Attribute::new("inputmode", Typed("::dioxus_nui::InputMode"), "Specifies a hint to the browser for which virtual keyboard to display."),
+    Attribute::new("is", Str, "Defines the type of the element."),
+    Attribute::new("itemid", Str, "Specifies a URL which designates a page which describes the offering."),
+    Attribute::new("itemprop", Str, "Associates an element with one or more items within an HTML document."),
+    Attribute::new("itemref", Str, "Specifies additional items to include in the accessibility tree."),
+    Attribute::new("itemtype", Str, "Specifies the URL(s) of the vocabulary that defines the item(s) for an element."),
+    Attribute::new("lang", Str, "Specifies the primary language for the element's contents."),
+    Attribute::new("nonce", Str, "Provides a mechanism to enable the server to declare a policy for the page to report back to it when a nonce is used."),
+    Attribute::new("part", Str, "Assigns a part name to an element, so that it can be used from the element's CSS, JavaScript or other parts of the page."),
+    Attribute::new("role", Str, "Defines a role name for an element."),
+    Attribute::new("slot", Str, "Assigns a slot name to an element."),
+    Attribute::new("spellcheck", Typed("bool"), "Specifies whether the element may be checked for spelling errors."),
+    Attribute::new("style", Str, "Specifies an inline CSS style for an element."),
+    Attribute::new("tabindex", Int, "Specifies the tabbing order for an element."),
+    Attribute::new("title", Str, "Defines a title for the element."),
+    Attribute::new("translate", YesNo, "Specifies whether the element's attribute values and the values of its Text node children are to be translated when the page is localized."),
+
+    // ARIA
+    Attribute::aria("aria_label", "aria-label", Str, "Defines a string value that labels the element."),
+    Attribute::aria("aria_labelledby", "aria-labelledby", Str, "Identifies the element (or elements) that labels the element."),
+    Attribute::aria("aria_describedby", "aria-describedby", Str, "Identifies the element (or elements) that describes the element."),
+    Attribute::aria("aria_controls", "aria-controls", Str, "Identifies the element (or elements) whose contents or presence are controlled by the element."),
+    Attribute::aria("aria_current", "aria-current", Str, "Indicates the element that represents the current item within a container or set of related elements."),
+    Attribute::aria("aria_expanded", "aria-expanded", Typed("bool"), "Indicates whether the element, or another grouping element it controls, is expanded or collapsed."),
+    Attribute::aria("aria_pressed", "aria-pressed", Typed("::dioxus_nui::Tristate"), "Indicates the current \"pressed\" state of toggle buttons."),
+    Attribute::aria("aria_checked", "aria-checked", Typed("::dioxus_nui::Tristate"), "Indicates the current \"checked\" state of checkboxes, radio buttons, and other widgets."),
+    Attribute::aria("aria_selected", "aria-selected", Typed("bool"), "Indicates the current \"selected\" state of various widgets."),
+    Attribute::aria("aria_disabled", "aria-disabled", Typed("bool"), "Indicates that the element is perceivable but disabled, so it is not editable or otherwise operable."),
+    Attribute::aria("aria_hidden", "aria-hidden", Typed("bool"), "Indicates whether the element is exposed to an accessibility API."),
+    Attribute::aria("aria_invalid", "aria-invalid", Typed("bool"), "Indicates the entered value does not conform to the format expected by the application."),
+    Attribute::aria("aria_required", "aria-required", Typed("bool"), "Indicates that user input is required on the element before a form may be submitted."),
+    Attribute::aria("aria_busy", "aria-busy", Typed("bool"), "Indicates an element is being modified and that assistive technologies may want to wait until the modifications are complete."),
+    Attribute::aria("aria_live", "aria-live", Typed("::dioxus_nui::AriaLive"), "Indicates that an element will be updated, and describes the types of updates the user agents, assistive technologies, and user can expect."),
+];
+
+pub(crate) const EVENTS: &[Event] = &[
+    // Every element
+    Event::new("onblur", "FocusEvent", Common, "Triggers when an element loses focus."),
+    Event::new("onclick", "MouseEvent", Common, "Triggers when an element is clicked."),
+    Event::new("oncontextmenu", "MouseEvent", Common, "Triggers when the context menu is triggered by right-clicking on an element."),
+    Event::new("ondblclick", "MouseEvent", Common, "Triggers when an element is double-clicked."),
+    Event::new("ondrag", "DragEvent", Common, "Triggers when an element is being dragged."),
+    Event::new("ondragend", "DragEvent", Common, "Triggers when the dragging of an element is finished."),
+    Event::new("ondragenter", "DragEvent", Common, "Triggers when a dragged element enters a drop target."),
+    Event::new("ondragleave", "DragEvent", Common, "Triggers when a dragged element leaves a drop target."),
+    Event::new("ondragover", "DragEvent", Common, "Triggers when an element is being dragged over a drop target."),
+    Event::new("ondragstart", "DragEvent", Common, "Triggers when the dragging of an element starts."),
+    Event::new("ondrop", "DragEvent", Common, "Triggers when a dragged element is dropped on a drop target."),
+    Event::new("onfocus", "FocusEvent", Common, "Triggers when an element receives focus."),
+    Event::new("onkeydown", "KeyboardEvent", Common, "Triggers when a key is pressed down while the element is in focus."),
+    Event::new("onkeypress", "KeyboardEvent", Common, "Triggers when a key is pressed while the element is in focus."),
+    Event::new("onkeyup", "KeyboardEvent", Common, "Triggers when a key is released while the element is in focus."),
+    Event::new("onmousedown", "MouseEvent", Common, "Triggers when a mouse button is pressed down on an element."),
+    Event::new("onmouseenter", "MouseEvent", Common, "Triggers when the mouse pointer enters an element."),
+    Event::new("onmouseleave", "MouseEvent", Common, "Triggers when the mouse pointer leaves an element."),
+    Event::new("onmousemove", "MouseEvent", Common, "Triggers when the mouse pointer is moving over an element."),
+    Event::new("onmouseout", "MouseEvent", Common, "Triggers when the mouse pointer leaves an element, or one of its child elements, and enters another element."),
+    Event::new("onmouseover", "MouseEvent", Common, "Triggers when the mouse pointer enters an element, or one of its child elements, from another element."),
+    Event::new("onmouseup", "MouseEvent", Common, "Triggers when a mouse button is released on an element."),
+    Event::new("onscroll", "ScrollEvent", Common, "Triggers when an element is being scrolled."),
+    Event::new("ontoggle", "ToggleEvent", Common, "Triggers when a checkbox element is toggled between on and off states."),
+    Event::new("onfocusin", "FocusEvent", Common, "Triggers when an element, or one of its descendants, is about to receive focus."),
+    Event::new("onfocusout", "FocusEvent", Common, "Triggers when an element, or one of its descendants, is about to lose focus."),
+    Event::new("onpointerdown", "PointerEvent", Common, "Triggers when a pointer (mouse, pen or touch) becomes active over an element."),
+    Event::new("onpointermove", "PointerEvent", Common, "Triggers when a pointer changes coordinates over an element."),
+    Event::new("onpointerup", "PointerEvent", Common, "Triggers when a pointer is no longer active over an element."),
+    Event::new("onpointercancel", "PointerEvent", Common, "Triggers when the browser decides there are unlikely to be more pointer events."),
+    Event::new("onpointerenter", "PointerEvent", Common, "Triggers when a pointer enters an element."),
+    Event::new("onpointerleave", "PointerEvent", Common, "Triggers when a pointer leaves an element."),
+    Event::new("onpointerover", "PointerEvent", Common, "Triggers when a pointer enters an element, or one of its child elements."),
+    Event::new("onpointerout", "PointerEvent", Common, "Triggers when a pointer leaves an element, or one of its child elements."),
+    Event::new("ongotpointercapture", "PointerEvent", Common, "Triggers when an element captures a pointer."),
+    Event::new("onlostpointercapture", "PointerEvent", Common, "Triggers when a captured pointer is released."),
+    Event::new("ontouchstart", "TouchEvent", Common, "Triggers when a touch point is placed on an element."),
+    Event::new("ontouchmove", "TouchEvent", Common, "Triggers when a touch point is moved along an element."),
+    Event::new("ontouchend", "TouchEvent", Common, "Triggers when a touch point is removed from an element."),
+    Event::new("ontouchcancel", "TouchEvent", Common, "Triggers when a touch point has been disrupted."),
+    Event::new("onwheel", "WheelEvent", Common, "Triggers when a wheel button of a pointing device is rotated over an element."),
+    Event::new("oncopy", "ClipboardEvent", Common, "Triggers when the user copies the content of an element."),
+    Event::new("oncut", "ClipboardEvent", Common, "Triggers when the user cuts the content of an element."),
+    Event::new("onpaste", "ClipboardEvent", Common, "Triggers when the user pastes content into an element."),
+    Event::new("onanimationstart", "AnimationEvent", Common, "Triggers when a CSS animation starts."),
+    Event::new("onanimationiteration", "AnimationEvent", Common, "Triggers when an iteration of a CSS animation ends and another one begins."),
+    Event::new("onanimationend", "AnimationEvent", Common, "Triggers when a CSS animation completes."),
+    Event::new("ontransitionend", "TransitionEvent", Common, "Triggers when a CSS transition completes."),
+    Event::new("onmounted", "MountedEvent", Common, "Triggers when the element is mounted, gives access to it (see [`ElementHandle`](::dioxus_nui::ElementHandle))."),
+
+    // Form elements (`input`, `textarea`, `select`, `form`)
+    Event::new("onchange", "FormEvent", Form, "Triggers when the value of a form element is changed."),
+    Event::new("oninput", "FormEvent", Form, "Triggers when the value of a form element is being changed."),
+    Event::new("oninvalid", "FormEvent", Form, "Triggers when a form element's value is invalid."),
+    Event::new("onreset", "FormEvent", Form, "Triggers when a form element is reset."),
+    Event::new("onselect", "SelectionEvent", Form, "Triggers when the text within a textarea or text input is selected by the user."),
+    Event::new("onsubmit", "FormEvent", Form, "Triggers when a form is submitted."),
+
+    // Media elements (`img`, `audio`, `video`)
+    Event::new("onabort", "MediaEvent", Media, "Triggers when a media playback is aborted."),
+    Event::new("ondurationchange", "MediaEvent", Media, "Triggers when the duration of a media element is changed."),
+    Event::new("onemptied", "MediaEvent", Media, "Triggers when a media element is emptied (e.g. via the \"pause\" button)."),
+    Event::new("onended", "MediaEvent", Media, "Triggers when a media element has ended playback."),
+    Event::new("onerror", "ImageEvent", Media, "Triggers when an error occurs while loading an image."),
+    Event::new("onload", "ImageEvent", Media, "Triggers when an image is finished loading."),
+    Event::new("onloadeddata", "MediaEvent", Media, "Triggers when the media data is loaded and ready to be played."),
+    Event::new("onloadedmetadata", "MediaEvent", Media, "Triggers when the metadata (e.g. length) of a media element is loaded."),
+    Event::new("onloadstart", "MediaEvent", Media, "Triggers when the loading of a media element starts."),
+    Event::new("onpause", "MediaEvent", Media, "Triggers when a media element is paused."),
+    Event::new("onplay", "MediaEvent", Media, "Triggers when a media element starts playing."),
+    Event::new("onplaying", "MediaEvent", Media, "Triggers when a media element has started playing."),
+    Event::new("onprogress", "MediaEvent", Media, "Triggers when a media element is progressing through its playback."),
+    Event::new("onratechange", "MediaEvent", Media, "Triggers when the playback rate of a media element is changed."),
+    Event::new("onseeked", "MediaEvent", Media, "Triggers when a media element has finished seeking to a new position."),
+    Event::new("onseeking", "MediaEvent", Media, "Triggers when a media element is seeking a new position."),
+    Event::new("onstalled", "MediaEvent", Media, "Triggers when a media element is trying to load but eventually stalling."),
+    Event::new("onsuspend", "MediaEvent", Media, "Triggers when the loading of a media element is suspended."),
+    Event::new("ontimeupdate", "MediaEvent", Media, "Triggers when the current playback position of a media element is updated."),
+    Event::new("onvolumechange", "MediaEvent", Media, "Triggers when the volume of a media element is changed."),
+    Event::new("onwaiting", "MediaEvent", Media, "Triggers when a media element is waiting for data to load."),
+];
+
+/// Returns the global attributes, except the ones shadowed by a field in `own`.
+fn attributes(own: &[String]) -> impl Iterator<Item = &'static Attribute> + '_ {
+    ATTRIBUTES
+        .iter()
+        .filter(move |attribute| !own.iter().any(|field| field == attribute.name))
+}
+
+/// Returns the events of the common group and `groups`, except the ones shadowed by a field in `own`.
+fn events<'a>(groups: &'a [Group], own: &'a [String]) -> impl Iterator<Item = &'static Event> + 'a {
+    EVENTS.iter().filter(move |event| {
+        (event.group == Common || groups.contains(&event.group))
+            && !own.iter().any(|field| field == event.name)
+    })
+}
+
+/// Returns the fields added to a component's properties, borrowing for `lifetime`.
+///
+/// Only the events of the common group and `groups` are added.
+///
+/// `Option` and `EventHandler` are not written with their full path, as `#[derive(Props)]` recognizes them by name.
+///
+/// Besides the global attributes and events, adds `data` and `attributes`, which are spread into the element,
+/// and `class` and `accent`, which are used by `$CLASS`.
+///
+/// Global attributes and events named like a field in `own` are skipped, the component's property takes their place.
+pub(crate) fn fields(lifetime: &Lifetime, groups: &[Group], own: &[String]) -> Vec<Field> {
+    let attributes = attributes(own).map(
+        |Attribute {
+             name, kind, doc, ..
+         }| {
+            let name = format_ident!("{name}");
+            let ty = match kind {
+                Str => quote!(&#lifetime str),
+                Typed(ty) => syn::parse_str(ty).expect("invalid attribute type"),
+                Flag | YesNo => quote!(bool),
+                Int => quote!(i32),
+            };
+            parse_quote! {
+                #[doc = #doc]
+                #[props(into)]
+                #name: Option<#ty>
+            }
+        },
+    );
+
+    let events = events(groups, own).map(
+        |Event {
+             name, event, doc, ..
+         }| {
+            let name = format_ident!("{name}");
+            let event = format_ident!("{event}");
+            parse_quote! {
+                #[doc = #doc]
+                #name: Option<EventHandler<#lifetime, ::dioxus::prelude::#event>>
+            }
+        },
+    );
+
+    let nui: [Field; 4] = [
+        parse_quote! {
+            /// Custom `data-*` attributes, used to store data private to the page or application.
+            ///
+            /// Names are given without the `data-` prefix: `data: &[("testid", "submit")]` renders `data-testid="submit"`.
+            #[props(default)]
+            data: &#lifetime [(&#lifetime str, &#lifetime str)]
+        },
+        parse_quote! {
+            /// Extra attributes forwarded as is to the root element, for the ones that don't have a property.
+            ///
+            /// For example: `attributes: &[("form", "login")]`.
+            #[props(default)]
+            attributes: &#lifetime [(&#lifetime str, &#lifetime str)]
+        },
+        parse_quote! {
+            /// Specifies one or more class names for an element.
+            ///
+            /// Accepts a `&str` or a [`Classes`](::dioxus_nui::class::Classes).
+            #[props(into)]
+            class: Option<::dioxus_nui::class::Classes<#lifetime>>
+        },
+        parse_quote! {
+            /// Sets accent, will work with most elements.
+            #[props(default)]
+            accent: bool
+        },
+    ];
+
+    attributes.chain(events).chain(nui).collect()
+}
+
+/// Expands `$GLOBALS` into the global attributes and events, using the span of the placeholder.
+///
+/// Attributes and events in `explicit` (by property or HTML name) are skipped, as the element already sets them.
+///
+/// Which attributes and events a component has depends on its groups and on the properties it declares itself,
+/// so they are added by its `GlobalAttributes` implementation and spread into the element along with the extra attributes.
+/// Attributes that were not set and events without a handler are not rendered.
+///
+/// `extra` (from `$GLOBALS: expr`) are attributes added by the component, global ones with the same name are skipped.
+pub(crate) fn forward(span: Span, explicit: &[String], extra: Option<&Expr>) -> TokenStream {
+    let explicit = explicit.iter().map(|name| LitStr::new(name, span));
+
+    match extra {
+        None => quote_spanned! {span=>
+            ..{
+                let mut attributes = ::dioxus_nui::__private::extra_attributes(cx, cx.props.data, cx.props.attributes);
+                ::dioxus_nui::__private::GlobalAttributes::globals(cx.props, cx, &[#(#explicit),*], &mut attributes);
+                attributes
+            }
+        },
+        Some(extra) => quote_spanned! {span=>
+            ..{
+                let extra: ::std::vec::Vec<::dioxus::core::Attribute> = ::std::iter::IntoIterator::into_iter(#extra).collect();
+                let skip: ::std::vec::Vec<&str> = [#(#explicit),*]
+                    .into_iter()
+                    .chain(extra.iter().map(|attribute| attribute.name))
+                    .collect();
+                let mut attributes = ::dioxus_nui::__private::extra_attributes(cx, cx.props.data, cx.props.attributes);
+                ::dioxus_nui::__private::GlobalAttributes::globals(cx.props, cx, &skip, &mut attributes);
+                attributes.extend(extra);
+                attributes
+            }
+        },
+    }
+}
+
+/// Implements `GlobalAttributes` for the properties `item`, rendering the global attributes and the events
+/// of the common group and `groups`, except the ones shadowed by a field in `own`.
+pub(crate) fn globals(item: &ItemStruct, lifetime: &Lifetime, groups: &[Group], own: &[String]) -> TokenStream {
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let attributes = attributes(own).map(|attribute| {
+        let field = format_ident!("{}", attribute.name);
+        let name = attribute.name;
+        let html = attribute.html_name();
+        let value = match attribute.kind {
+            Str => quote!(self.#field),
+            Typed(_) => quote!(::dioxus_nui::__private::MapStr::map_str(self.#field)),
+            Flag => quote!(::dioxus_nui::__private::flag(self.#field)),
+            YesNo => quote!(::dioxus_nui::__private::yes_no(self.#field)),
+            Int => quote!(self.#field.map(i64::from)),
+        };
+        quote! {
+            if !skip.contains(&#name) && !skip.contains(&#html) {
+                attributes.extend(::dioxus_nui::__private::attribute(cx, #html, #value));
+            }
+        }
+    });
+
+    let events = events(groups, own).map(|Event { name, .. }| {
+        let field = format_ident!("{name}");
+        quote! {
+            if !skip.contains(&#name) {
+                attributes.extend(::dioxus_nui::__private::listener(cx, #name, &self.#field));
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::dioxus_nui::__private::GlobalAttributes<#lifetime> for #ident #ty_generics #where_clause {
+            fn globals(
+                &#lifetime self,
+                cx: &#lifetime ::dioxus::core::ScopeState,
+                skip: &[&str],
+                attributes: &mut ::std::vec::Vec<::dioxus::core::Attribute<#lifetime>>,
+            ) {
+                #(#attributes)*
+                #(#events)*
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Implementation of the [`render_component!`](crate::render_component) macro.
+//!
+//! The input is walked as a token stream, so every placeholder keeps the span of the user's code
+//! and errors point at the offending tokens instead of panicking inside the macro.
+
+use proc_macro2::{Group, Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::{Error, Expr, LitStr, Result};
+
+use crate::global;
+
+/// Expands the body of a `render_component!` call.
+pub(crate) fn render_component(input: TokenStream) -> Result<TokenStream> {
+    let mut children = None;
+    let body = substitute(input, &mut children)?;
+
+    Ok(quote! {
+        ::dioxus::prelude::render! {
+            ::dioxus_nui::CheckIfUninit {}
+            #body
+        }
+    })
+}
+
+/// Replaces every placeholder in `input`, recursing into nested elements.
+///
+/// `children` holds the span of the `$CHILDREN` already substituted, as they can only be rendered once.
+fn substitute(input: TokenStream, children: &mut Option<Span>) -> Result<TokenStream> {
+    let mut output = TokenStream::new();
+    // Placeholders already used in this element, to reject duplicates
+    let mut class = None;
+    let mut globals = None;
+    let explicit = explicit_attributes(&input);
+
+    let mut tokens = input.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(dollar) if dollar.as_char() == '$' => {
+                let placeholder = match tokens.next() {
+                    Some(TokenTree::Ident(ident)) => ident,
+                    Some(other) => return Err(Error::new(other.span(), EXPECTED_PLACEHOLDER)),
+                    None => return Err(Error::new(dollar.span(), EXPECTED_PLACEHOLDER)),
+                };
+                let span = placeholder.span();
+
+                match placeholder.to_string().as_str() {
+                    "CLASS" => {
+                        check_unique(&mut class, &placeholder)?;
+
+                        match tokens.next() {
+                            Some(TokenTree::Punct(colon)) if colon.as_char() == ':' => {}
+                            Some(other) => return Err(Error::new(other.span(), "expected `:` after `$CLASS`")),
+                            None => return Err(Error::new(span, "expected `$CLASS: \"...\"`")),
+                        }
+
+                        // The value ends at the next comma, commas inside groups or literals are part of it
+                        let mut value = TokenStream::new();
+                        while let Some(token) = tokens.next_if(|t| !is_comma(t)) {
+                            value.extend([token]);
+                        }
+                        if value.is_empty() {
+                            return Err(Error::new(span, "expected a value after `$CLASS:`"));
+                        }
+
+                        output.extend(class_attribute(span, value)?);
+                    }
+                    "GLOBALS" => {
+                        check_unique(&mut globals, &placeholder)?;
+
+                        // Optional extra attributes, `$GLOBALS: expr`
+                        let extra = match tokens.next_if(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ':')) {
+                            Some(_) => {
+                                let mut value = TokenStream::new();
+                                while let Some(token) = tokens.next_if(|t| !is_comma(t)) {
+                                    value.extend([token]);
+                                }
+                                if value.is_empty() {
+                                    return Err(Error::new(span, "expected an expression after `$GLOBALS:`"));
+                                }
+                                Some(syn::parse2::<Expr>(value)?)
+                            }
+                            None => None,
+                        };
+
+                        output.extend(global::forward(span, &explicit, extra.as_ref()));
+                    }
+                    "CHILDREN" => {
+                        if children.replace(span).is_some() {
+                            return Err(Error::new(span, "`$CHILDREN` can only be used once"));
+                        }
+                        output.extend(quote_spanned!(span=> &cx.props.children));
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            span,
+                            format!("unknown placeholder `${placeholder}`, expected `$CLASS`, `$GLOBALS` or `$CHILDREN`"),
+                        ))
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut substituted =
+                    Group::new(group.delimiter(), substitute(group.stream(), children)?);
+                substituted.set_span(group.span());
+                output.extend([TokenTree::Group(substituted)]);
+            }
+            token => output.extend([token]),
+        }
+    }
+
+    Ok(output)
+}
+
+const EXPECTED_PLACEHOLDER: &str = "expected `$CLASS`, `$GLOBALS` or `$CHILDREN` after `$`";
+
+/// Returns an error if `placeholder` was already used in the current element.
+fn check_unique(used: &mut Option<Span>, placeholder: &Ident) -> Result<()> {
+    match used.replace(placeholder.span()) {
+        Some(_) => Err(Error::new(
+            placeholder.span(),
+            format!("`${placeholder}` can only be used once per element"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the attributes set at the top level of an element (`name: value` or `"name": value`).
+fn explicit_attributes(input: &TokenStream) -> Vec<String> {
+    let tokens: Vec<TokenTree> = input.clone().into_iter().collect();
+
+    tokens
+        .windows(2)
+        .enumerate()
+        // Attributes are at the start of the element or after a comma
+        .filter(|(i, _)| *i == 0 || is_comma(&tokens[i - 1]))
+        .filter_map(|(_, pair)| match pair {
+            [name, TokenTree::Punct(colon)]
+                if colon.as_char() == ':' && colon.spacing() == Spacing::Alone =>
+            {
+                match name {
+                    TokenTree::Ident(name) => Some(name.unraw().to_string()),
+                    TokenTree::Literal(name) => syn::parse_str::<LitStr>(&name.to_string())
+                        .ok()
+                        .map(|name| name.value()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_comma(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(p) if p.as_char() == ',')
+}
+
+/// Builds the `class` attribute from the value of `$CLASS`, the `accent` property and the user-specified `class`.
+///
+/// The value can be a format string (with the same syntax as in `rsx!`) or any expression implementing `Display`.
+fn class_attribute(span: Span, value: TokenStream) -> Result<TokenStream> {
+    let value = match syn::parse2::<LitStr>(value.clone()) {
+        Ok(lit) => format_string(&lit)?,
+        Err(_) => {
+            let value = syn::parse2::<Expr>(value)?;
+            quote! { ::std::format_args!("{}", #value) }
+        }
+    };
+
+    Ok(quote_spanned! {span=>
+        class: ::dioxus_nui::__private::merge_classes(
+            cx,
+            #value,
+            cx.props.accent,
+            &cx.props.class
+        )
+    })
+}
+
+/// Converts an `rsx!` format string (like `"{class::btn} {style}"`) into a `format_args!` call.
+///
+/// Unlike `format_args!`, arbitrary expressions are allowed inside the braces.
+fn format_string(lit: &LitStr) -> Result<TokenStream> {
+    let input = lit.value();
+    let mut format = String::new();
+    let mut args = Vec::new();
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.next_if_eq(&'{').is_some() => format.push_str("{{"),
+            '}' if chars.next_if_eq(&'}').is_some() => format.push_str("}}"),
+            '}' => {
+                return Err(Error::new(
+                    lit.span(),
+                    "unmatched `}` in format string, use `}}` to escape it",
+                ))
+            }
+            '{' => {
+                // Braces can be nested inside the expression (blocks, closures, ...)
+                let mut segment = String::new();
+                let mut depth = 0usize;
+                loop {
+                    match chars.next() {
+                        Some('}') if depth == 0 => break,
+                        Some(c) => {
+                            match c {
+                                '{' => depth += 1,
+                                '}' => depth -= 1,
+                                _ => {}
+                            }
+                            segment.push(c);
+                        }
+                        None => {
+                            return Err(Error::new(
+                                lit.span(),
+                                "unmatched `{` in format string, use `{{` to escape it",
+                            ))
+                        }
+                    }
+                }
+
+                let (expr, spec) = split_format_spec(&segment);
+                let expr: Expr = syn::parse_str(expr).map_err(|e| {
+                    Error::new(
+                        lit.span(),
+                        format!("invalid expression `{expr}` in format string: {e}"),
+                    )
+                })?;
+
+                format.push('{');
+                if let Some(spec) = spec {
+                    format.push(':');
+                    format.push_str(spec);
+                }
+                format.push('}');
+                args.push(respan(expr.into_token_stream(), lit.span()));
+            }
+            c => format.push(c),
+        }
+    }
+
+    let format = LitStr::new(&format, lit.span());
+    Ok(quote_spanned!(lit.span()=> ::std::format_args!(#format, #(#args),*)))
+}
+
+/// Splits a format segment into the expression and the format spec (`{value:>8}`).
+///
+/// The spec starts after the last `:` outside of brackets and closure parameters, so path separators
+/// (`::`), type ascriptions in closures (`|x: u8| ...`) and struct literals are part of the expression.
+fn split_format_spec(segment: &str) -> (&str, Option<&str>) {
+    let bytes = segment.as_bytes();
+    let mut depth = 0usize;
+    let mut in_closure_params = false;
+    let mut separator = None;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            // `||` is either an empty closure or a logical or, neither has parameters
+            b'|' if bytes.get(i + 1) == Some(&b'|') => i += 1,
+            b'|' if depth == 0 => in_closure_params = !in_closure_params,
+            b':' if bytes.get(i + 1) == Some(&b':') => i += 1,
+            b':' if depth == 0 && !in_closure_params => separator = Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    match separator {
+        Some(i) => (&segment[..i], Some(&segment[i + 1..])),
+        None => (segment, None),
+    }
+}
+
+/// Sets the span of every token in `tokens`, so errors inside a string literal point at it.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_format_spec;
+
+    #[test]
+    fn format_spec() {
+        assert_eq!(split_format_spec("value"), ("value", None));
+        assert_eq!(split_format_spec("value:>8"), ("value", Some(">8")));
+        assert_eq!(split_format_spec("class::btn"), ("class::btn", None));
+        assert_eq!(split_format_spec("class::btn:?"), ("class::btn", Some("?")));
+        assert_eq!(split_format_spec("value as u8"), ("value as u8", None));
+        assert_eq!(split_format_spec("(|x: u8| x + 1)(2)"), ("(|x: u8| x + 1)(2)", None));
+        assert_eq!(split_format_spec("(|x: u8| x)(2):03"), ("(|x: u8| x)(2)", Some("03")));
+        assert_eq!(split_format_spec("f(a || b):x"), ("f(a || b)", Some("x")));
+        assert_eq!(split_format_spec("Point { x: 1, y: 2 }.x"), ("Point { x: 1, y: 2 }.x", None));
+        assert_eq!(split_format_spec("a[b::c]:<4"), ("a[b::c]", Some("<4")));
+    }
+}
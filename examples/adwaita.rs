@@ -3,8 +3,11 @@
 use dioxus::prelude::*;
 use dioxus_nui::prelude::include_css;
 use dioxus_nui::{
-    class, Align, Button, ButtonStyle, InitNui, Input, InputType, List, ListItem, Theme, H1, H2,
-    H3, H4,
+    class::{self, Classes},
+    use_element_handle, Accordion, AccordionSection, Align, AutocompleteEntry, Button, ButtonStyle,
+    Checkbox, Dropdown, ExpanderRow, InitNui, Input, InputType, List, ListItem, Orientation,
+    PasswordEntry, PasswordStrength, RadioGroup, RovingGroup, Slider, SpinButton, Switch, TextArea,
+    Theme, H1, H2, H3, H4,
 };
 
 fn main() {
@@ -12,6 +15,15 @@ fn main() {
     dioxus_desktop::launch(App);
 }
 fn App(cx: Scope) -> Element {
+    let email = use_element_handle(cx);
+    let number = use_state(cx, || 0);
+    let checked = use_state(cx, || false);
+    let active = use_state(cx, || true);
+    let size = use_state(cx, || "M");
+    let volume = use_state(cx, || 50.0);
+    let brightness = use_state(cx, || 50.0);
+    let language = use_state(cx, || "rs");
+
     render! {
         // Include useful classes for the example
         style { include_css!("examples/index.css") }
@@ -22,31 +34,34 @@ fn App(cx: Scope) -> Element {
         div { class: "nui-example-root",
             // Headers
             div {
-                H1 { class: "{class::accent_hover}", "Title 1" }
+                H1 { class: class::accent_hover, "Title 1" }
                 H2 { "Title 2" }
                 H3 { "Title 3" }
                 H4 { "Title 4" }
             }
 
             // Buttons
+            // Single tab stop, move between the buttons with the arrow keys
             div { class: "nui-example-buttongrid",
-                Button { button_style: ButtonStyle::Regular, onclick: move |_| println!("It works!!!"), "Regular" }
-                Button { accent: true, "Accent" }
-                Button { disabled: true, onclick: move |_| println!("Should not print anything"), "Disabled" }
-                Button { button_style: ButtonStyle::Compact, "Compact" }
-                Button { button_style: ButtonStyle::Pill, "Pill" }
-                Button { button_style: ButtonStyle::Circular, "C" }
+                RovingGroup {
+                    Button { button_style: ButtonStyle::Regular, onclick: move |_| println!("It works!!!"), "Regular" }
+                    Button { accent: true, "Accent" }
+                    Button { disabled: true, onclick: move |_| println!("Should not print anything"), "Disabled" }
+                    Button { button_style: ButtonStyle::Compact, "Compact" }
+                    Button { button_style: ButtonStyle::Pill, "Pill" }
+                    Button { button_style: ButtonStyle::Circular, "C" }
+                }
             }
 
             // List
             List { class: "nui-example-list",
                 ListItem {
-                    class: "{class::accent_hover} {class::h1}",
+                    class: Classes::from(class::accent_hover).add(class::h1),
                     title: "Lists",
                     align: dioxus_nui::Align::Center
                 }
-                ListItem { title: "Title", subtitle: "Subtitle" }
-                ListItem { title: "Only Title" }
+                ListItem { title: "Title", subtitle: "Subtitle", onclick: move |_| println!("Clicked") }
+                ListItem { title: "Only Title", onclick: move |_| println!("Clicked") }
                 ListItem { subtitle: "Only Subtitle" }
                 ListItem { title: "Left aligned (Default)", subtitle: "align: Align::Left" }
                 ListItem {
@@ -71,7 +86,84 @@ fn App(cx: Scope) -> Element {
                     align: Align::Center
                 }
             }
-            
+
+            // Toggles
+            List { class: "nui-example-list",
+                ListItem {
+                    title: "Checkbox",
+                    suffix: render! { Checkbox { checked: *checked.get(), on_toggle: move |c| checked.set(c) } }
+                }
+                ListItem {
+                    title: "Indeterminate checkbox",
+                    suffix: render! { Checkbox { indeterminate: true } }
+                }
+                ListItem {
+                    title: "Radio group",
+                    RadioGroup {
+                        aria_label: "Size",
+                        orientation: Orientation::Horizontal,
+                        options: &[("S", "Small"), ("M", "Medium"), ("L", "Large")],
+                        selected: *size.get(),
+                        on_select: move |s| size.set(s)
+                    }
+                }
+                ListItem {
+                    title: "Switch",
+                    suffix: render! { Switch { aria_label: "Switch", active: *active.get(), on_toggle: move |a| active.set(a) } }
+                }
+                ListItem {
+                    title: "Slider ({brightness})",
+                    Slider {
+                        aria_label: "Brightness",
+                        value: *brightness.get(),
+                        step: 10.0,
+                        marks: &[(0.0, Some("Off")), (50.0, None), (100.0, Some("Max"))],
+                        on_change: move |v| brightness.set(v),
+                        on_commit: move |v| println!("Committed {v}")
+                    }
+                }
+                ListItem {
+                    title: "Dropdown",
+                    suffix: render! {
+                        Dropdown {
+                            aria_label: "Language",
+                            options: &[("rs", "Rust"), ("py", "Python"), ("go", "Go"), ("c", "C"), ("cpp", "C++"), ("js", "JavaScript")],
+                            selected: *language.get(),
+                            searchable: true,
+                            on_select: move |l| language.set(l)
+                        }
+                    }
+                }
+                ListItem {
+                    title: "Spin button",
+                    subtitle: "Arrow keys, Page Up/Down and the mouse wheel work too",
+                    suffix: render! {
+                        SpinButton {
+                            aria_label: "Volume",
+                            value: *volume.get(),
+                            min: 0.0,
+                            max: 100.0,
+                            step: 5.0,
+                            on_change: move |v| volume.set(v)
+                        }
+                    }
+                }
+            }
+
+            // Expanders
+            List { class: "nui-example-list",
+                ExpanderRow {
+                    title: "Expander row",
+                    subtitle: "Click to show the nested rows",
+                    ListItem { title: "Nested row" }
+                    ListItem { title: "Another nested row" }
+                }
+            }
+            Accordion { class: "nui-example-list",
+                AccordionSection { title: "First section", default_expanded: true, p { "Only one section is open at a time." } }
+                AccordionSection { title: "Second section", p { "Use `multiple: true` to allow more." } }
+            }
+
             // Inputs
             List {
                 ListItem {
@@ -106,10 +198,48 @@ fn App(cx: Scope) -> Element {
                     on_change: move |v| println!("{v}")
                     input_type: InputType::DatetimeLocal
                 }
+                PasswordEntry {
+                    label: "Password:"
+                    strength: &PasswordStrength::estimate
+                    on_change: move |v| println!("{v}")
+                }
+                TextArea {
+                    label: "Notes:"
+                    max_length: 200
+                    counter: true
+                    auto_grow: true
+                    on_change: move |v| println!("{v}")
+                }
+                TextArea {
+                    label: "Code:"
+                    monospace: true
+                    wrap: dioxus_nui::TextWrap::Off
+                    value: "fn main() {{\n    println!(\"Hello\");\n}}"
+                }
+                AutocompleteEntry {
+                    label: "Favorite fruit:"
+                    suggestions: &["Apple", "Apricot", "Banana", "Blueberry", "Cherry", "Grape", "Mango", "Pineapple"]
+                    on_select: move |v| println!("Selected {v}")
+                }
+                Input {
+                    label: "Bound to a number ({number}):"
+                    bind: number
+                }
                 Input {
                     label: "Enter your email:"
                     on_change: move |v| println!("{v}")
                     input_type: InputType::Email
+                    onmounted: move |e| email.set(e)
+                }
+                Button {
+                    button_style: ButtonStyle::Compact,
+                    onclick: move |_| {
+                        to_owned![email];
+                        cx.spawn(async move {
+                            let _ = email.focus().await;
+                        });
+                    },
+                    "Focus email"
                 }
                 Input {
                     on_change: move |v| println!("{v}"),
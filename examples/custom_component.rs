@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+//! Shows how to build a component outside of `dioxus-nui` that behaves like the NUI ones.
+
+use dioxus::prelude::*;
+use dioxus_nui::{class, nui_component, render_component, InitNui, Theme};
+
+fn main() {
+    dioxus_desktop::launch(App);
+}
+
+/// Adds the global attributes and events, `class` and `accent`.
+#[nui_component]
+#[derive(Props)]
+pub struct BadgeProps<'a> {
+    children: Element<'a>,
+}
+
+/// Small label that forwards every global attribute and event, like a NUI `Button`.
+pub fn Badge<'a>(cx: Scope<'a, BadgeProps<'a>>) -> Element<'a> {
+    render_component! {
+        span {
+            $CLASS: "{class::label} badge",
+            $GLOBALS,
+            $CHILDREN
+        }
+    }
+}
+
+fn App(cx: Scope) -> Element {
+    render! {
+        InitNui { theme: Theme::Adwaita }
+
+        Badge { "Regular" }
+        Badge { accent: true, onclick: move |_| println!("Clicked!"), "Accent" }
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(non_snake_case)]
+
+//! Renders a NUI app to a static HTML document.
+//!
+//! Run with `cargo run --example ssr --features ssr > page.html`.
+
+use dioxus::prelude::*;
+use dioxus_nui::{ssr, Button, InitNui, List, ListItem, Theme, H1};
+
+fn main() {
+    println!("{}", ssr::render(App).to_document());
+}
+
+fn App(cx: Scope) -> Element {
+    render! {
+        InitNui { theme: Theme::Adwaita }
+
+        H1 { "Rendered on the server" }
+        List {
+            ListItem { title: "Static HTML", subtitle: "The stylesheet is placed in <head>" }
+            ListItem {
+                title: "Same components",
+                suffix: render! { Button { "Button" } }
+            }
+        }
+    }
+}
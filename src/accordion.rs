@@ -1 +1,408 @@
-// TODO: Accordion for reference: https://www.youtube.com/watch?v=B_n4YONte5A
\ No newline at end of file
+use crate::{
+    class::{self, Classes},
+    icons::ChevronDown,
+    use_roving_item, Align, RovingFocus, RovingItem,
+};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+use std::{cell::Cell, rc::Rc};
+
+/// Sections open in an `Accordion`, shared with its `AccordionSection`s.
+struct AccordionState {
+    multiple: bool,
+    open: Vec<ScopeId>,
+}
+
+impl AccordionState {
+    /// Adds a section, without `multiple` only the first one expanded by default is open.
+    fn register(&mut self, id: ScopeId, default_expanded: bool) {
+        if default_expanded && (self.multiple || self.open.is_empty()) {
+            self.open.push(id);
+        }
+    }
+
+    fn set(&mut self, id: ScopeId, expanded: bool) {
+        self.open.retain(|open| *open != id);
+        if expanded {
+            // Only one section can be open at a time
+            if !self.multiple {
+                self.open.clear();
+            }
+            self.open.push(id);
+        }
+    }
+}
+
+/// Removes a section from its `Accordion` when it's dropped.
+struct Registration {
+    accordion: UseSharedState<AccordionState>,
+    id: ScopeId,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.accordion
+            .write_silent()
+            .open
+            .retain(|open| *open != self.id);
+    }
+}
+
+/// Uncontrolled expanded state of a section, stored in its `Accordion` if it's inside one.
+#[derive(Clone, Copy)]
+struct Expansion<'a> {
+    local: &'a UseState<bool>,
+    accordion: Option<&'a UseSharedState<AccordionState>>,
+    id: ScopeId,
+}
+
+impl Expansion<'_> {
+    fn get(&self) -> bool {
+        match self.accordion {
+            Some(accordion) => accordion.read().open.contains(&self.id),
+            None => *self.local.get(),
+        }
+    }
+
+    fn set(&self, expanded: bool) {
+        let Some(accordion) = self.accordion else {
+            self.local.set(expanded);
+            return;
+        };
+
+        accordion.write().set(self.id, expanded);
+    }
+}
+
+/// Provided by `ExpanderRow` to its nested rows, updated on every render to whether they're hidden.
+#[derive(Clone, Default)]
+pub(crate) struct Collapsed(Rc<Cell<bool>>);
+
+impl Collapsed {
+    pub(crate) fn get(&self) -> bool {
+        self.0.get()
+    }
+
+    pub(crate) fn set(&self, collapsed: bool) {
+        self.0.set(collapsed);
+    }
+}
+
+fn use_expansion(cx: &ScopeState, default_expanded: bool, in_accordion: bool) -> Expansion<'_> {
+    let local = use_state(cx, || default_expanded);
+    // Always called, hooks must be in the same order on every render
+    let accordion = use_shared_state::<AccordionState>(cx).filter(|_| in_accordion);
+    let id = cx.scope_id();
+
+    cx.use_hook(|| {
+        accordion.map(|accordion| {
+            accordion.write_silent().register(id, default_expanded);
+
+            Registration {
+                accordion: accordion.clone(),
+                id,
+            }
+        })
+    });
+
+    Expansion {
+        local,
+        accordion,
+        id,
+    }
+}
+
+#[nui_component]
+#[derive(Props)]
+pub struct AccordionProps<'a> {
+    /// Allows more than one section to be open at the same time.
+    ///
+    /// `false` by default, opening a section closes the others.
+    #[props(default)]
+    multiple: bool,
+    children: Element<'a>,
+}
+
+/// Group of [`AccordionSection`]s, where only one (or more, with `multiple`) can be open at a time.
+///
+/// # Example
+/// ```
+/// Accordion {
+///     AccordionSection { title: "General", default_expanded: true, "..." }
+///     AccordionSection { title: "Privacy", "..." }
+/// }
+/// ```
+pub fn Accordion<'a>(cx: Scope<'a, AccordionProps<'a>>) -> Element<'a> {
+    use_shared_state_provider(cx, || AccordionState {
+        multiple: cx.props.multiple,
+        open: Vec::new(),
+    });
+    if let Some(accordion) = use_shared_state::<AccordionState>(cx) {
+        if accordion.read().multiple != cx.props.multiple {
+            accordion.write_silent().multiple = cx.props.multiple;
+        }
+    }
+
+    render_component! {
+        div {
+            $CLASS: "{class::accordion}",
+            $GLOBALS,
+            $CHILDREN
+        }
+    }
+}
+
+#[nui_component]
+#[derive(Props)]
+pub struct AccordionSectionProps<'a> {
+    /// Title of the section, always visible.
+    title: &'a str,
+
+    /// Whether the section is open, makes it controlled (`on_toggle` must update it).
+    expanded: Option<bool>,
+
+    /// Whether the section is open on the first render, if `expanded` is not set.
+    #[props(default)]
+    default_expanded: bool,
+
+    /// Called with the new state when the title is clicked.
+    on_toggle: Option<EventHandler<'a, bool>>,
+
+    /// Content of the section, shown when it's open.
+    children: Element<'a>,
+}
+
+/// Section of an [`Accordion`], with a title that opens and closes it.
+///
+/// Works on its own too, outside of an `Accordion`.
+pub fn AccordionSection<'a>(cx: Scope<'a, AccordionSectionProps<'a>>) -> Element<'a> {
+    let AccordionSectionProps {
+        title,
+        expanded,
+        default_expanded,
+        on_toggle,
+        ..
+    } = cx.props;
+
+    let expansion = use_expansion(cx, *default_expanded, true);
+    let is_expanded = expanded.unwrap_or_else(|| expansion.get());
+    let id = cx.scope_id().0;
+
+    render_component! {
+        div {
+            $CLASS: Classes::from(class::accordion_section).when(is_expanded, class::expanded),
+            $GLOBALS,
+            button {
+                class: "{class::accordion_header}",
+                r#type: "button",
+                id: "nui-expander-{id}-header",
+                "aria-expanded": "{is_expanded}",
+                "aria-controls": "nui-expander-{id}",
+                onclick: move |_| {
+                    if expanded.is_none() {
+                        expansion.set(!is_expanded);
+                    }
+                    if let Some(on_toggle) = on_toggle {
+                        on_toggle.call(!is_expanded);
+                    }
+                },
+                span { class: "{class::accordion_title}", *title }
+                span { class: "{class::expander_chevron}", ChevronDown {} }
+            }
+            div {
+                class: "{class::expander_content}",
+                id: "nui-expander-{id}",
+                role: "region",
+                "aria-labelledby": "nui-expander-{id}-header",
+                div { class: "{class::expander_content_inner}", $CHILDREN }
+            }
+        }
+    }
+}
+
+#[nui_component]
+#[derive(Props)]
+pub struct ExpanderRowProps<'a> {
+    /// Title of the row.
+    title: Option<&'a str>,
+
+    /// Subtitle of the row.
+    subtitle: Option<&'a str>,
+
+    /// Alignment of the Title and Subtitle attributes.
+    ///
+    /// `Align::Left` by default.
+    #[props(default)]
+    align: Align,
+
+    /// Prefix of the row, can be any element.
+    prefix: Option<Element<'a>>,
+
+    /// Whether the row is expanded, makes it controlled (`on_toggle` must update it).
+    expanded: Option<bool>,
+
+    /// Whether the row is expanded on the first render, if `expanded` is not set.
+    #[props(default)]
+    default_expanded: bool,
+
+    /// Called with the new state when the row is clicked.
+    on_toggle: Option<EventHandler<'a, bool>>,
+
+    /// Nested rows (usually [`ListItem`](crate::ListItem)s), shown when the row is expanded.
+    children: Element<'a>,
+}
+
+/// Row of a [`List`](crate::List) that reveals nested rows when clicked, like `AdwExpanderRow`.
+///
+/// The header is moved to with the arrow keys like the other actionable items of the `List`,
+/// and so are the actionable nested rows while the row is expanded.
+///
+/// # Example
+/// ```
+/// List {
+///     ExpanderRow {
+///         title: "Advanced",
+///         subtitle: "Only if you know what you're doing",
+///         ListItem { title: "Nested row" }
+///     }
+/// }
+/// ```
+pub fn ExpanderRow<'a>(cx: Scope<'a, ExpanderRowProps<'a>>) -> Element<'a> {
+    let ExpanderRowProps {
+        title,
+        subtitle,
+        align,
+        prefix,
+        expanded,
+        default_expanded,
+        on_toggle,
+        ..
+    } = cx.props;
+
+    let expansion = use_expansion(cx, *default_expanded, false);
+    let is_expanded = expanded.unwrap_or_else(|| expansion.get());
+    let id = cx.scope_id().0;
+
+    // Taken before providing our own, which would be found first afterwards
+    let parent = cx.use_hook(|| cx.consume_context::<Collapsed>());
+    let parent_collapsed = parent.as_ref().map_or(false, Collapsed::get);
+    let group = cx.use_hook(|| cx.consume_context::<RovingFocus>());
+    let roving = use_roving_item(cx, true);
+    cx.use_hook(|| {
+        // Unlike the content of other items, the nested rows are part of the `List`'s group
+        if let Some(group) = group {
+            cx.provide_context(group.clone());
+        }
+    });
+    cx.use_hook(|| cx.provide_context(Collapsed::default()))
+        .set(parent_collapsed || !is_expanded);
+
+    let title = title.map(|t| {
+        rsx! {
+            p { class: "{class::list_item_title}", t }
+        }
+    });
+
+    let subtitle = subtitle.map(|t| {
+        rsx! {
+            p { class: "{class::list_item_subtitle}", t }
+        }
+    });
+
+    let prefix = prefix.as_ref().map(|p| {
+        rsx! {
+            div { class: "{class::list_item_prefix}", p }
+        }
+    });
+
+    render_component! {
+        div {
+            $CLASS: Classes::from(class::expander).when(is_expanded, class::expanded),
+            role: cx.props.role.unwrap_or("listitem"),
+            $GLOBALS,
+            button {
+                class: "{class::expander_header}",
+                r#type: "button",
+                id: "nui-expander-{id}-header",
+                tabindex: roving.and_then(RovingItem::tabindex).map(i64::from),
+                "data-nui-prevent-keys": roving.and_then(RovingItem::prevent_keys),
+                "aria-expanded": "{is_expanded}",
+                "aria-controls": "nui-expander-{id}",
+                onclick: move |_| {
+                    if expanded.is_none() {
+                        expansion.set(!is_expanded);
+                    }
+                    if let Some(on_toggle) = on_toggle {
+                        on_toggle.call(!is_expanded);
+                    }
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    if let Some(roving) = roving {
+                        roving.keydown(cx, &e);
+                    }
+                },
+                onfocus: move |_| {
+                    if let Some(roving) = roving {
+                        roving.activate();
+                    }
+                },
+                onmounted: move |e: MountedEvent| {
+                    if let Some(roving) = roving {
+                        roving.mounted(e);
+                    }
+                },
+                prefix,
+                div { class: "{align}",
+                    title,
+                    subtitle,
+                },
+                span { class: "{class::expander_chevron}", ChevronDown {} }
+            }
+            div {
+                class: "{class::expander_content}",
+                id: "nui-expander-{id}",
+                role: "list",
+                div { class: "{class::expander_content_inner}", $CHILDREN }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccordionState;
+    use dioxus::prelude::ScopeId;
+
+    #[test]
+    fn single_open() {
+        let mut state = AccordionState {
+            multiple: false,
+            open: Vec::new(),
+        };
+        state.register(ScopeId(1), true);
+        state.register(ScopeId(2), true);
+        state.register(ScopeId(3), false);
+        assert_eq!(state.open, [ScopeId(1)]);
+
+        state.set(ScopeId(3), true);
+        assert_eq!(state.open, [ScopeId(3)]);
+
+        state.set(ScopeId(3), false);
+        assert!(state.open.is_empty());
+    }
+
+    #[test]
+    fn multiple_open() {
+        let mut state = AccordionState {
+            multiple: true,
+            open: Vec::new(),
+        };
+        state.register(ScopeId(1), true);
+        state.register(ScopeId(2), true);
+        state.set(ScopeId(3), true);
+        assert_eq!(state.open, [ScopeId(1), ScopeId(2), ScopeId(3)]);
+
+        state.set(ScopeId(1), false);
+        assert_eq!(state.open, [ScopeId(2), ScopeId(3)]);
+    }
+}
@@ -0,0 +1,64 @@
+//! Types of the [ARIA](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes) attributes accepted by every NUI component.
+
+use crate::ToStr;
+
+/// Value of the `aria-checked` and `aria-pressed` attributes.
+///
+/// Can be created from a `bool`, `Mixed` is used for partially checked/pressed elements.
+///
+/// # Example
+/// ```
+/// use dioxus_nui::{Button, Tristate};
+/// Button { aria_pressed: Tristate::Mixed, "Bold" }
+/// // Same as `Tristate::True`
+/// Button { aria_pressed: true, "Italic" }
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Tristate {
+    True,
+    False,
+    Mixed,
+}
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        if value {
+            Tristate::True
+        } else {
+            Tristate::False
+        }
+    }
+}
+
+impl ToStr<'static> for Tristate {
+    fn to_str(&self) -> &'static str {
+        match self {
+            Tristate::True => "true",
+            Tristate::False => "false",
+            Tristate::Mixed => "mixed",
+        }
+    }
+}
+
+/// Value of the `aria-live` attribute.
+///
+/// Tells assistive technologies how to announce the updates of an element.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AriaLive {
+    /// Updates are not announced.
+    Off,
+    /// Updates are announced when the user is idle.
+    Polite,
+    /// Updates are announced immediately.
+    Assertive,
+}
+
+impl ToStr<'static> for AriaLive {
+    fn to_str(&self) -> &'static str {
+        match self {
+            AriaLive::Off => "off",
+            AriaLive::Polite => "polite",
+            AriaLive::Assertive => "assertive",
+        }
+    }
+}
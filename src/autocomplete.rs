@@ -0,0 +1,389 @@
+use crate::{class, Input, ToStr};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+use futures_timer::Delay;
+use std::{cell::Cell, future::Future, ops::Range, pin::Pin, rc::Rc, time::Duration};
+
+/// Future returned by a [`Suggestions::Source`].
+pub type SuggestionsFuture = Pin<Box<dyn Future<Output = Vec<String>>>>;
+
+/// Where the suggestions of an [`AutocompleteEntry`] come from.
+///
+/// # Example
+/// ```
+/// // Filtered by the typed text
+/// let fruits: Suggestions = (&["Apple", "Banana", "Cherry"]).into();
+///
+/// // Computed from the typed text
+/// let files = Suggestions::from_fn(|text| list_files(text));
+///
+/// // Fetched, only once the user stops typing
+/// let users = Suggestions::from_async(|text| async move { search_users(&text).await });
+/// ```
+#[derive(Clone)]
+pub enum Suggestions<'a> {
+    /// Fixed list, filtered by the typed text ignoring case.
+    List(&'a [&'a str]),
+    /// Called with the typed text, after the debounce delay.
+    Source(Rc<dyn Fn(String) -> SuggestionsFuture>),
+}
+
+impl<'a> Suggestions<'a> {
+    /// Suggestions computed synchronously from the typed text.
+    pub fn from_fn(source: impl Fn(&str) -> Vec<String> + 'static) -> Self {
+        Suggestions::Source(Rc::new(move |text| {
+            Box::pin(std::future::ready(source(&text)))
+        }))
+    }
+
+    /// Suggestions computed asynchronously from the typed text, like a request to a server.
+    ///
+    /// Results of outdated requests are discarded.
+    pub fn from_async<F>(source: impl Fn(String) -> F + 'static) -> Self
+    where
+        F: Future<Output = Vec<String>> + 'static,
+    {
+        Suggestions::Source(Rc::new(move |text| Box::pin(source(text))))
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for Suggestions<'a> {
+    fn from(list: &'a [&'a str]) -> Self {
+        Suggestions::List(list)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [&'a str; N]> for Suggestions<'a> {
+    fn from(list: &'a [&'a str; N]) -> Self {
+        Suggestions::List(list)
+    }
+}
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct AutocompleteEntryProps<'a> {
+    /// Where the suggestions come from, see [`Suggestions`].
+    #[props(into)]
+    suggestions: Suggestions<'a>,
+
+    /// Initial text of the entry.
+    value: Option<&'a str>,
+    name: Option<&'a str>,
+    label: Option<&'a str>,
+
+    /// Time without typing before asking a [`Suggestions::Source`], 200 ms by default.
+    #[props(default = Duration::from_millis(200))]
+    debounce: Duration,
+
+    /// Maximum number of suggestions shown, 8 by default.
+    #[props(default = 8)]
+    max_suggestions: usize,
+
+    /// Called with the text every time it changes, including when a suggestion is selected.
+    on_change: Option<EventHandler<'a, String>>,
+
+    /// Called with the suggestion selected with Enter or a click.
+    on_select: Option<EventHandler<'a, String>>,
+}
+
+/// Text entry that suggests completions while typing, built on [`Input`](crate::Input).
+///
+/// The part of each suggestion matching the text is highlighted.
+/// The arrow keys move between the suggestions, Enter selects one and Escape hides them.
+///
+/// The global attributes and events are forwarded to the input, `class` and `accent` are applied to the whole entry.
+/// While a [`Suggestions::Source`] is asked, the previous suggestions are hidden and the input is marked as busy.
+///
+/// # Example
+/// ```
+/// render! {
+///     AutocompleteEntry {
+///         label: "Fruit",
+///         suggestions: &["Apple", "Banana", "Cherry"],
+///         on_select: move |fruit| println!("{fruit}")
+///     }
+/// }
+/// ```
+pub fn AutocompleteEntry<'a>(cx: Scope<'a, AutocompleteEntryProps<'a>>) -> Element<'a> {
+    let AutocompleteEntryProps {
+        suggestions,
+        value,
+        name,
+        label,
+        debounce,
+        max_suggestions,
+        on_change,
+        on_select,
+        oninput,
+        onkeydown,
+        onblur,
+        ..
+    } = cx.props;
+
+    let text = use_state(cx, || value.unwrap_or_default().to_string());
+    let fetched = use_state(cx, Vec::<String>::new);
+    // Waiting for the suggestions of a `Source`
+    let busy = use_state(cx, || false);
+    let open = use_state(cx, || false);
+    let highlighted = use_state(cx, || None::<usize>);
+    let requests = &*cx.use_hook(|| Rc::new(Requests::default()));
+    let id: &'a str = cx.use_hook(|| format!("nui-autocomplete-{}", cx.scope_id().0));
+
+    let shown: Vec<&str> = match suggestions {
+        Suggestions::List(list) => list
+            .iter()
+            .copied()
+            .filter(|suggestion| !text.is_empty() && find_ignore_case(suggestion, text).is_some())
+            .take(*max_suggestions)
+            .collect(),
+        Suggestions::Source(_) => fetched
+            .get()
+            .iter()
+            .map(String::as_str)
+            .take(*max_suggestions)
+            .collect(),
+    };
+    let shown: &'a [&'a str] = cx.bump().alloc_slice_copy(&shown);
+    let expanded = *open.get() && !shown.is_empty();
+    let current = highlighted.get().filter(|&i| i < shown.len());
+
+    let choose = move |suggestion: &str| {
+        text.set(suggestion.to_string());
+        open.set(false);
+        highlighted.set(None);
+        if let Some(on_select) = on_select {
+            on_select.call(suggestion.to_string());
+        }
+        if let Some(on_change) = on_change {
+            on_change.call(suggestion.to_string());
+        }
+    };
+
+    // Moving the caret and submitting the form are replaced by the navigation in the suggestions
+    let prevent_keys = match (!shown.is_empty(), expanded, current.is_some()) {
+        (true, true, true) => "ArrowUp ArrowDown Enter",
+        (true, true, false) => "ArrowUp ArrowDown",
+        (true, false, _) => "ArrowDown",
+        (false, ..) => "",
+    };
+    let active_descendant = current
+        .filter(|_| expanded)
+        .map(|i| cx.raw_text(format_args!("{id}-option-{i}")));
+
+    // `Input` has no properties for these, they are added to the extra attributes supplied by the user
+    let attributes: Vec<(&str, &str)> = cx
+        .props
+        .attributes
+        .iter()
+        .copied()
+        .chain([
+            ("autocomplete", "off"),
+            ("aria-autocomplete", "list"),
+            ("data-nui-prevent-keys", prevent_keys),
+        ])
+        .chain(active_descendant.map(|id| ("aria-activedescendant", id)))
+        // Not rendered if `None`, which the property of `Input` can't be set to
+        .chain(name.map(|name| ("name", name)))
+        .collect();
+    let attributes: &'a [(&'a str, &'a str)] = cx.bump().alloc_slice_copy(&attributes);
+
+    render_component! {
+        div {
+            $CLASS: "{class::autocomplete}",
+            Input {
+                label: label.unwrap_or_default(),
+                value: text.get().as_str(),
+                role: "combobox",
+                aria_expanded: expanded,
+                aria_controls: cx.raw_text(format_args!("{id}-listbox")),
+                aria_busy: *busy.get(),
+                data: cx.props.data,
+                attributes: attributes,
+                // Handled here, or set on the input above
+                globals: (cx.props, &["oninput", "onkeydown", "onblur", "role", "aria-expanded", "aria-controls", "aria-busy"]),
+                oninput: move |e: Event<FormData>| {
+                    text.set(e.value.clone());
+                    open.set(!e.value.is_empty());
+                    highlighted.set(None);
+
+                    if let Suggestions::Source(source) = suggestions {
+                        // The previous suggestions are for another text
+                        fetched.set(Vec::new());
+                        busy.set(!e.value.is_empty());
+                        let request = requests.start();
+
+                        if !e.value.is_empty() {
+                            let (source, requests, fetched, busy) = (source.clone(), requests.clone(), fetched.clone(), busy.clone());
+                            let (value, debounce) = (e.value.clone(), *debounce);
+                            cx.spawn(async move {
+                                Delay::new(debounce).await;
+                                if !requests.is_latest(request) {
+                                    return;
+                                }
+                                let suggestions = source(value).await;
+                                if requests.is_latest(request) {
+                                    fetched.set(suggestions);
+                                    busy.set(false);
+                                }
+                            });
+                        }
+                    }
+
+                    if let Some(on_change) = on_change {
+                        on_change.call(e.value.clone());
+                    }
+                    if let Some(oninput) = oninput {
+                        oninput.call(e);
+                    }
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    match e.key() {
+                        Key::ArrowDown if !shown.is_empty() => {
+                            open.set(true);
+                            highlighted.set(Some(current.map_or(0, |i| (i + 1).min(shown.len() - 1))));
+                        }
+                        Key::ArrowUp if expanded => {
+                            highlighted.set(Some(current.map_or(0, |i| i.saturating_sub(1))));
+                        }
+                        Key::Enter if expanded => {
+                            if let Some(i) = current {
+                                choose(shown[i]);
+                            }
+                        }
+                        Key::Escape => open.set(false),
+                        _ => {}
+                    }
+                    if let Some(onkeydown) = onkeydown {
+                        onkeydown.call(e);
+                    }
+                },
+                onblur: move |e: FocusEvent| {
+                    open.set(false);
+                    if let Some(onblur) = onblur {
+                        onblur.call(e);
+                    }
+                },
+            }
+            if expanded {
+                rsx! {
+                    div {
+                        id: "{id}-listbox",
+                        class: "{class::autocomplete_popup}",
+                        role: "listbox",
+                        for (i, suggestion) in shown.iter().enumerate() {
+                            div {
+                                // Suggestions can be repeated, like the results of a `Source`
+                                key: "{i}",
+                                id: "{id}-option-{i}",
+                                class: "{class::autocomplete_option}",
+                                role: "option",
+                                "aria-selected": (current == Some(i)).to_str(),
+                                // Keeps the focus in the entry, so it's not closed before the click
+                                prevent_default: "onmousedown",
+                                onmousedown: |_| {},
+                                onmouseenter: move |_| highlighted.set(Some(i)),
+                                onclick: move |_| choose(suggestion),
+                                highlight(suggestion, text)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Counts the requests made to a [`Suggestions::Source`], so only the results of the latest one are shown.
+#[derive(Default)]
+struct Requests(Cell<u64>);
+
+impl Requests {
+    /// Starts a request, the previous ones are outdated.
+    fn start(&self) -> u64 {
+        let request = self.0.get() + 1;
+        self.0.set(request);
+        request
+    }
+
+    /// Whether no request was started after `request`.
+    fn is_latest(&self, request: u64) -> bool {
+        self.0.get() == request
+    }
+}
+
+/// Splits `suggestion` around the first match of `text`, which is rendered in a `mark`.
+fn highlight<'a>(suggestion: &'a str, text: &str) -> LazyNodes<'a, 'a> {
+    let (before, matched, after) = split_match(suggestion, text);
+
+    rsx! {
+        before,
+        if !matched.is_empty() {
+            rsx! { mark { class: "{class::autocomplete_match}", matched } }
+        }
+        after
+    }
+}
+
+/// Returns the parts of `suggestion` before, in and after the first match of `text`, everything is before if none.
+fn split_match<'a>(suggestion: &'a str, text: &str) -> (&'a str, &'a str, &'a str) {
+    let range = find_ignore_case(suggestion, text).unwrap_or(suggestion.len()..suggestion.len());
+    (
+        &suggestion[..range.start],
+        &suggestion[range.clone()],
+        &suggestion[range.end..],
+    )
+}
+
+/// Finds `query` in `text` ignoring case, returns the byte range of the match.
+fn find_ignore_case(text: &str, query: &str) -> Option<Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut chars = text[start..].chars();
+        let mut end = start;
+        for q in query.chars() {
+            let c = chars.next()?;
+            if !c.to_lowercase().eq(q.to_lowercase()) {
+                return None;
+            }
+            end += c.len_utf8();
+        }
+        Some(start..end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_ignore_case, split_match, Requests};
+
+    #[test]
+    fn finds_ignoring_case() {
+        assert_eq!(find_ignore_case("Banana", "an"), Some(1..3));
+        assert_eq!(find_ignore_case("Banana", "BAN"), Some(0..3));
+        assert_eq!(find_ignore_case("Banana", "kiwi"), None);
+        assert_eq!(find_ignore_case("Banana", ""), None);
+        // Byte ranges, which can be used to slice the suggestion
+        assert_eq!(find_ignore_case("Crème brûlée", "BRÛ"), Some(7..11));
+        assert_eq!(&"Crème brûlée"[7..11], "brû");
+    }
+
+    #[test]
+    fn splits_match() {
+        assert_eq!(split_match("Banana", "NAN"), ("Ba", "nan", "a"));
+        assert_eq!(split_match("Apple", "apple"), ("", "Apple", ""));
+        assert_eq!(split_match("Apple", "kiwi"), ("Apple", "", ""));
+    }
+
+    #[test]
+    fn latest_request() {
+        let requests = Requests::default();
+        let first = requests.start();
+        assert!(requests.is_latest(first));
+        // Typing again before the debounce delay discards the first request
+        let second = requests.start();
+        assert!(!requests.is_latest(first));
+        assert!(requests.is_latest(second));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::InputType;
+use dioxus::prelude::*;
+
+/// Value that can be bound to an [`Input`](crate::Input) with [`Binding`].
+///
+/// Implemented for `String`, `bool`, the number types and, with the `chrono` feature,
+/// `NaiveDate`, `NaiveTime` and `NaiveDateTime`.
+///
+/// Implement it to bind your own types.
+pub trait Bindable: Sized {
+    /// Type of the input used when none is specified.
+    const INPUT_TYPE: InputType;
+
+    /// Converts the value to the `value` attribute of the input.
+    fn to_value(&self) -> String;
+
+    /// Parses the value of the input, `None` if it's not valid.
+    fn from_value(value: &str) -> Option<Self>;
+
+    /// Returns the `checked` state of the input, only used by checkboxes.
+    fn checked(&self) -> Option<bool> {
+        None
+    }
+}
+
+impl Bindable for String {
+    const INPUT_TYPE: InputType = InputType::Text;
+
+    fn to_value(&self) -> String {
+        self.clone()
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        Some(value.to_owned())
+    }
+}
+
+impl Bindable for bool {
+    const INPUT_TYPE: InputType = InputType::Checkbox;
+
+    fn to_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+
+    fn checked(&self) -> Option<bool> {
+        Some(*self)
+    }
+}
+
+macro_rules! impl_bindable_number {
+    ($($number:ty),*) => {
+        $(
+            impl Bindable for $number {
+                const INPUT_TYPE: InputType = InputType::Number;
+
+                fn to_value(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_value(value: &str) -> Option<Self> {
+                    value.trim().parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_bindable_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(feature = "chrono")]
+impl Bindable for chrono::NaiveDate {
+    const INPUT_TYPE: InputType = InputType::Date;
+
+    fn to_value(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Bindable for chrono::NaiveTime {
+    const INPUT_TYPE: InputType = InputType::Time;
+
+    fn to_value(&self) -> String {
+        use chrono::Timelike;
+        // Seconds are only shown by the input if they're in the value
+        match self.second() {
+            0 => self.format("%H:%M").to_string(),
+            _ => self.format("%H:%M:%S").to_string(),
+        }
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        chrono::NaiveTime::parse_from_str(value, "%H:%M:%S")
+            .or_else(|_| chrono::NaiveTime::parse_from_str(value, "%H:%M"))
+            .ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Bindable for chrono::NaiveDateTime {
+    const INPUT_TYPE: InputType = InputType::DatetimeLocal;
+
+    fn to_value(&self) -> String {
+        use chrono::Timelike;
+        match self.second() {
+            0 => self.format("%Y-%m-%dT%H:%M").to_string(),
+            _ => self.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        }
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+            .ok()
+    }
+}
+
+/// Two-way binding between a [`UseState`] and an [`Input`](crate::Input).
+///
+/// The state is updated on every valid input, invalid values (like an incomplete number) are ignored.
+///
+/// Created from a `&UseState<T>` where `T` is [`Bindable`], which also sets the input type if none is specified.
+///
+/// # Example
+/// ```
+/// let name = use_state(cx, String::new);
+/// let age = use_state(cx, || 18u8);
+/// let subscribed = use_state(cx, || false);
+///
+/// render! {
+///     // Controlled, the input always shows the value of the state
+///     Input { label: "Name", bind: name }
+///     Input { label: "Age", bind: age }
+///     Input { label: "Subscribe", bind: subscribed }
+///
+///     // Uncontrolled, the input only takes the initial value of the state
+///     Input { label: "Notes", bind: Binding::uncontrolled(notes) }
+/// }
+/// ```
+pub struct Binding<'a> {
+    value: String,
+    checked: Option<bool>,
+    input_type: InputType,
+    controlled: bool,
+    set: Box<dyn Fn(&str) + 'a>,
+}
+
+impl<'a> Binding<'a> {
+    /// Binds `state` to the input, which is updated whenever `state` changes.
+    pub fn controlled<T: Bindable + 'static>(state: &'a UseState<T>) -> Self {
+        Binding {
+            value: state.get().to_value(),
+            checked: state.get().checked(),
+            input_type: T::INPUT_TYPE,
+            controlled: true,
+            set: Box::new(move |value| {
+                if let Some(value) = T::from_value(value) {
+                    state.set(value);
+                }
+            }),
+        }
+    }
+
+    /// Binds `state` to the input, which only takes its initial value and is not updated when `state` changes.
+    pub fn uncontrolled<T: Bindable + 'static>(state: &'a UseState<T>) -> Self {
+        Binding {
+            controlled: false,
+            ..Binding::controlled(state)
+        }
+    }
+
+    /// Current value of the state, as the `value` attribute of the input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Current `checked` state, only for checkboxes.
+    pub fn checked(&self) -> Option<bool> {
+        self.checked
+    }
+
+    /// Type of the input used when none is specified.
+    pub fn input_type(&self) -> InputType {
+        self.input_type
+    }
+
+    /// Returns `true` if the input is updated whenever the state changes.
+    pub fn is_controlled(&self) -> bool {
+        self.controlled
+    }
+
+    /// Parses `value` and updates the state if it's valid.
+    pub fn set(&self, value: &str) {
+        (self.set)(value)
+    }
+}
+
+impl<'a, T: Bindable + 'static> From<&'a UseState<T>> for Binding<'a> {
+    fn from(state: &'a UseState<T>) -> Self {
+        Binding::controlled(state)
+    }
+}
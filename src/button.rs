@@ -1,10 +1,8 @@
-use crate::{class, MapStr, ToStr};
+use crate::{class, use_roving_item, MapStr, RovingItem, ToStr, Tristate};
 use dioxus::prelude::*;
-use dioxus_nui_macros::render_component;
-use reusable::reuse;
+use dioxus_nui_macros::{nui_component, render_component};
 
-// `global_events` and `global_attributes` from crate::global
-#[reuse(global_events, global_attributes)]
+#[nui_component]
 #[derive(Props)]
 pub struct ButtonProps<'a> {
     children: Element<'a>,
@@ -12,6 +10,11 @@ pub struct ButtonProps<'a> {
     // Attributes
     disabled: Option<bool>,
 
+    /// Makes the button a toggle button, with `true` if it's pressed.
+    ///
+    /// Sets `aria-pressed`, takes precedence over the `aria_pressed` attribute.
+    pressed: Option<bool>,
+
     // Custom properties
     #[props(default)]
     button_style: ButtonStyle,
@@ -21,16 +24,25 @@ pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element {
     let ButtonProps {
         button_style,
         disabled,
+        pressed,
+        aria_pressed,
         ..
     } = cx.props;
+    // Part of a `RovingGroup`, if it's inside one
+    let roving = use_roving_item(cx, *disabled != Some(true));
 
     render_component! {
         button {
             $CLASS: "{class::btn} {button_style}",
 
             disabled: disabled.map_str(),
+            "aria-pressed": pressed.map(Tristate::from).or(*aria_pressed).map_str(),
+            tabindex: cx.props.tabindex.or(roving.and_then(RovingItem::tabindex)).map(i64::from),
+            "data-nui-prevent-keys": roving.and_then(RovingItem::prevent_keys),
 
-            $GLOBALS,
+            $GLOBALS: roving
+                .map(|item| item.listeners(cx, &cx.props.onkeydown, &cx.props.onfocus, &cx.props.onmounted))
+                .unwrap_or_default(),
             $CHILDREN
         }
     }
@@ -0,0 +1,118 @@
+use crate::{class::{self, Classes}, MapStr, ToStr};
+use dioxus::prelude::*;
+use std::rc::Rc;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct CheckboxProps<'a> {
+    /// Whether the checkbox is checked.
+    #[props(default)]
+    checked: bool,
+
+    /// Shows the checkbox as partially checked, useful for a checkbox that selects a group of them.
+    ///
+    /// Takes precedence over `checked`.
+    #[props(default)]
+    indeterminate: bool,
+
+    /// Text shown next to the checkbox.
+    label: Option<&'a str>,
+    name: Option<&'a str>,
+    disabled: Option<bool>,
+
+    /// Called with the new state when the checkbox is toggled.
+    ///
+    /// An indeterminate checkbox becomes checked.
+    on_toggle: Option<EventHandler<'a, bool>>,
+}
+
+/// Checkbox with a custom check mark, drawn by the stylesheet.
+///
+/// The global attributes and events are forwarded to the `<input>`, the class to the root `<label>`.
+///
+/// # Example
+/// ```
+/// let accepted = use_state(cx, || false);
+///
+/// render! {
+///     Checkbox {
+///         label: "Accept the terms",
+///         checked: *accepted.get(),
+///         on_toggle: move |checked| accepted.set(checked)
+///     }
+/// }
+/// ```
+pub fn Checkbox<'a>(cx: Scope<'a, CheckboxProps<'a>>) -> Element<'a> {
+    let CheckboxProps {
+        checked,
+        indeterminate,
+        label,
+        name,
+        disabled,
+        on_toggle,
+        onchange,
+        ..
+    } = cx.props;
+
+    let id = match cx.props.id {
+        Some(id) => id.to_string(),
+        None => format!("nui-checkbox-{}", cx.scope_id().0),
+    };
+    // `indeterminate` is a property of the element, not an attribute, and it's what assistive technologies read.
+    // The mark is drawn from the class, which is also there when rendering on the server (where it can't be set).
+    let eval = cx.use_hook(|| cx.consume_context::<Rc<dyn EvalProvider>>()).clone();
+    use_effect(cx, (*indeterminate,), {
+        let id = id.clone();
+        move |(indeterminate,)| async move {
+            if let Some(eval) = eval {
+                let _ = eval.new_evaluator(format!(
+                    "document.getElementById({id:?}).indeterminate = {indeterminate};"
+                ));
+            }
+        }
+    });
+
+    render_component! {
+        label {
+            $CLASS: Classes::from(class::checkbox).when(*indeterminate, class::checkbox_indeterminate),
+            input {
+                class: "{class::checkbox_input}",
+                r#type: "checkbox",
+                id: "{id}",
+                name: *name,
+                disabled: disabled.map_str(),
+                checked: (*checked && !indeterminate).to_str(),
+                onchange: move |e: Event<FormData>| {
+                    if let Some(on_toggle) = on_toggle {
+                        on_toggle.call(toggled(*checked, *indeterminate));
+                    }
+                    if let Some(onchange) = onchange {
+                        onchange.call(e);
+                    }
+                },
+                $GLOBALS,
+            }
+            span { class: "{class::checkbox_mark}", "aria-hidden": "true" }
+            *label
+        }
+    }
+}
+
+/// Returns the state of the checkbox after it's toggled, an indeterminate one becomes checked.
+fn toggled(checked: bool, indeterminate: bool) -> bool {
+    indeterminate || !checked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::toggled;
+
+    #[test]
+    fn toggle() {
+        assert!(toggled(false, false));
+        assert!(!toggled(true, false));
+        assert!(toggled(false, true));
+        assert!(toggled(true, true));
+    }
+}
@@ -5,20 +5,29 @@
 //! This module exposes the ones that can be used as a standalone class,
 //! which can be useful if you want to achieve a particular look that's not available through properties.
 //!
+//! Use [`Classes`] to combine them, every NUI component accepts it for `class` (and `title_class`, `subtitle_class`).
+//!
 //! # Example
 //! ```
 //! use dioxus_nui::{List, ListItem, Align};
-//! use dioxus_nui::class;
+//! use dioxus_nui::class::{self, Classes};
 //! List {
 //!     // Gives `title` the same styling as a `H1`
 //!     ListItem {
-//!         class: "{class::h1}",
+//!         title_class: class::h1,
 //!         title: "Interesting title",
 //!         align: Align::Center
 //!     }
+//!     // Highlights the item when hovered, only if it's selected
+//!     ListItem {
+//!         class: Classes::new().when(selected, class::accent_hover),
+//!         title: "Selectable title",
+//!     }
 //! }
 //! ```
 
+use std::fmt::{Display, Formatter};
+
 /// Styles element as a NUI `Button`.
 ///
 /// Prefer using the [`Button`](crate::Button) component directly.
@@ -88,6 +97,63 @@ pub(crate) const list_item_title: &str = "nui-list__item__title";
 pub(crate) const list_item_subtitle: &str = "nui-list__item__subtitle";
 pub(crate) const list_item_prefix: &str = "nui-list__item__prefix";
 pub(crate) const list_item_suffix: &str = "nui-list__item__suffix";
+pub(crate) const accordion: &str = "nui-accordion";
+pub(crate) const accordion_section: &str = "nui-accordion__section";
+pub(crate) const accordion_header: &str = "nui-accordion__header";
+pub(crate) const accordion_title: &str = "nui-accordion__title";
+pub(crate) const expander: &str = "nui-expander";
+pub(crate) const expander_header: &str = "nui-expander__header";
+pub(crate) const expander_chevron: &str = "nui-expander__chevron";
+pub(crate) const expander_content: &str = "nui-expander__content";
+pub(crate) const expander_content_inner: &str = "nui-expander__content-inner";
+pub(crate) const expanded: &str = "nui-expanded";
+pub(crate) const checkbox: &str = "nui-checkbox";
+pub(crate) const checkbox_input: &str = "nui-checkbox__input";
+pub(crate) const checkbox_mark: &str = "nui-checkbox__mark";
+pub(crate) const checkbox_indeterminate: &str = "nui-checkbox--indeterminate";
+pub(crate) const password: &str = "nui-password";
+pub(crate) const password_toggle: &str = "nui-password__toggle";
+pub(crate) const password_caps_lock: &str = "nui-password__caps-lock";
+pub(crate) const password_strength: &str = "nui-password__strength";
+pub(crate) const radio_group: &str = "nui-radio-group";
+pub(crate) const radio_group_horizontal: &str = "nui-radio-group--horizontal";
+pub(crate) const radio_group_vertical: &str = "nui-radio-group--vertical";
+pub(crate) const radio: &str = "nui-radio";
+pub(crate) const radio_input: &str = "nui-radio__input";
+pub(crate) const radio_mark: &str = "nui-radio__mark";
+pub(crate) const autocomplete: &str = "nui-autocomplete";
+pub(crate) const autocomplete_popup: &str = "nui-autocomplete__popup";
+pub(crate) const autocomplete_option: &str = "nui-autocomplete__option";
+pub(crate) const autocomplete_match: &str = "nui-autocomplete__match";
+pub(crate) const dropdown: &str = "nui-dropdown";
+pub(crate) const dropdown_button: &str = "nui-dropdown__button";
+pub(crate) const dropdown_label: &str = "nui-dropdown__label";
+pub(crate) const dropdown_placeholder: &str = "nui-dropdown__label--placeholder";
+pub(crate) const dropdown_backdrop: &str = "nui-dropdown__backdrop";
+pub(crate) const dropdown_popup: &str = "nui-dropdown__popup";
+pub(crate) const dropdown_search: &str = "nui-dropdown__search";
+pub(crate) const dropdown_list: &str = "nui-dropdown__list";
+pub(crate) const dropdown_option: &str = "nui-dropdown__option";
+pub(crate) const dropdown_empty: &str = "nui-dropdown__empty";
+pub(crate) const slider: &str = "nui-slider";
+pub(crate) const slider_horizontal: &str = "nui-slider--horizontal";
+pub(crate) const slider_vertical: &str = "nui-slider--vertical";
+pub(crate) const slider_input: &str = "nui-slider__input";
+pub(crate) const slider_marks: &str = "nui-slider__marks";
+pub(crate) const slider_mark: &str = "nui-slider__mark";
+pub(crate) const spin: &str = "nui-spin";
+pub(crate) const spin_entry: &str = "nui-spin__entry";
+pub(crate) const spin_button: &str = "nui-spin__button";
+pub(crate) const switch: &str = "nui-switch";
+pub(crate) const switch_thumb: &str = "nui-switch__thumb";
+pub(crate) const textarea: &str = "nui-textarea";
+pub(crate) const textarea_auto_grow: &str = "nui-textarea--auto-grow";
+pub(crate) const textarea_monospace: &str = "nui-textarea--monospace";
+pub(crate) const textarea_resize_none: &str = "nui-textarea--resize-none";
+pub(crate) const textarea_resize_vertical: &str = "nui-textarea--resize-vertical";
+pub(crate) const textarea_resize_horizontal: &str = "nui-textarea--resize-horizontal";
+pub(crate) const textarea_resize_both: &str = "nui-textarea--resize-both";
+pub(crate) const textarea_counter: &str = "nui-textarea__counter";
 
 /// Styles elements to the current's theme accent color, effect depends on the element.
 ///
@@ -112,3 +178,100 @@ pub const align_right: &str = "nui-align-right";
 ///
 /// Prefer using [`Align::Right`](crate::Align).
 pub const align_center: &str = "nui-align-center";
+
+/// Set of classes, displayed separated by spaces.
+///
+/// Each class is only added once, so a class can't be duplicated by accident.
+///
+/// # Example
+/// ```
+/// use dioxus_nui::class::{self, Classes};
+///
+/// let classes = Classes::from(class::h1)
+///     .add("custom another")
+///     .when(selected, class::accent)
+///     .add(class::h1);
+///
+/// assert_eq!(classes.to_string(), "nui-h1 custom another nui-accent");
+/// ```
+#[derive(Default, PartialEq, Eq, Debug, Clone)]
+pub struct Classes<'a>(Vec<&'a str>);
+
+impl<'a> Classes<'a> {
+    /// Creates an empty set of classes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `classes`, which can contain more than one class separated by whitespace.
+    ///
+    /// Classes already present are ignored.
+    pub fn add(mut self, classes: &'a str) -> Self {
+        for class in classes.split_whitespace() {
+            if !self.0.contains(&class) {
+                self.0.push(class);
+            }
+        }
+        self
+    }
+
+    /// Adds `classes` only if `condition` is `true`.
+    pub fn when(self, condition: bool, classes: &'a str) -> Self {
+        if condition {
+            self.add(classes)
+        } else {
+            self
+        }
+    }
+
+    /// Adds all the classes of `other`, `None` adds nothing.
+    pub fn merge<'b>(self, other: impl Into<Option<&'b Classes<'a>>>) -> Self
+    where
+        'a: 'b,
+    {
+        other
+            .into()
+            .into_iter()
+            .flat_map(|other| other.iter())
+            .fold(self, Classes::add)
+    }
+
+    /// Returns `true` if `class` is present.
+    pub fn contains(&self, class: &str) -> bool {
+        self.0.contains(&class)
+    }
+
+    /// Returns `true` if there are no classes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the classes, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl<'a> From<&'a str> for Classes<'a> {
+    fn from(classes: &'a str) -> Self {
+        Classes::new().add(classes)
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Classes<'a> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        iter.into_iter().fold(Classes::new(), Classes::add)
+    }
+}
+
+impl Display for Classes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, class) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(class)?;
+        }
+        Ok(())
+    }
+}
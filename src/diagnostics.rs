@@ -0,0 +1,26 @@
+//! Warnings about misuses of the components, only reported in debug builds.
+//!
+//! With the `tracing` feature they're emitted as `tracing` events (target `dioxus_nui`), if not they're printed to stderr.
+
+use dioxus::prelude::ScopeState;
+use std::cell::Cell;
+
+/// Reports a misuse of `$component`, with a message formatted like `format!`.
+macro_rules! misuse {
+    ($component:literal, $($arg:tt)+) => {
+        #[cfg(all(debug_assertions, feature = "tracing"))]
+        ::tracing::warn!(target: "dioxus_nui", component = $component, $($arg)+);
+        #[cfg(all(debug_assertions, not(feature = "tracing")))]
+        eprintln!("NUI warning: {}: {}", $component, format_args!($($arg)+));
+    };
+}
+
+pub(crate) use misuse;
+
+/// Returns `true` only on the first render of the component, so each misuse is reported once.
+///
+/// Always `false` in release builds, so the checks written after it like `use_first_render(cx) && ...` are skipped.
+pub(crate) fn use_first_render(cx: &ScopeState) -> bool {
+    // A constant, so the hook is either called on every render or never
+    cfg!(debug_assertions) && cx.use_hook(|| Cell::new(true)).replace(false)
+}
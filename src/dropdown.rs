@@ -0,0 +1,391 @@
+use crate::{class, icons::ChevronDown, use_element_handle, use_localization, MapStr, ToStr};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+use futures_timer::Delay;
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+/// Time without typing after which the typed characters are forgotten, like in a native select.
+const TYPING_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[nui_component]
+#[derive(Props)]
+pub struct DropdownProps<'a, T: PartialEq + Clone + 'a> {
+    /// Values of the options and their labels.
+    options: &'a [(T, &'a str)],
+
+    /// Value of the selected option, the placeholder is shown if `None` or if it's not in `options`.
+    selected: Option<T>,
+
+    /// Called with the value of the option when it's selected.
+    on_select: Option<EventHandler<'a, T>>,
+
+    /// Shown when no option is selected, `Messages::select` by default.
+    placeholder: Option<&'a str>,
+
+    /// Adds a field to filter the options by their label, useful for long lists.
+    #[props(default)]
+    searchable: bool,
+
+    disabled: Option<bool>,
+}
+
+/// Button that opens a list of options to select one, like `GtkDropDown`.
+///
+/// With the button focused, the arrow keys, Enter and Space open the list, and typing the start of a label selects it.
+/// The typed characters are forgotten after a second without typing.
+/// In the list, the arrow keys move between the options, Enter selects one and Escape closes it.
+///
+/// Drawn like the drop-down of the [`Theme`](crate::Theme): `GtkDropDown`, `QComboBox`, the WinUI `ComboBox` or the pop-up button of macOS.
+///
+/// # Example
+/// ```
+/// #[derive(PartialEq, Clone, Copy)]
+/// enum Language { Rust, Python, Go }
+///
+/// let language = use_state(cx, || Language::Rust);
+///
+/// render! {
+///     Dropdown {
+///         aria_label: "Language",
+///         options: &[(Language::Rust, "Rust"), (Language::Python, "Python"), (Language::Go, "Go")],
+///         selected: *language.get(),
+///         on_select: move |l| language.set(l)
+///     }
+/// }
+/// ```
+pub fn Dropdown<'a, T: PartialEq + Clone + 'a>(cx: Scope<'a, DropdownProps<'a, T>>) -> Element<'a> {
+    let DropdownProps {
+        options,
+        selected,
+        on_select,
+        placeholder,
+        searchable,
+        disabled,
+        onclick,
+        onkeydown,
+        onmounted,
+        ..
+    } = cx.props;
+
+    let open = use_state(cx, || false);
+    // Option under the keyboard or the pointer, index in `options`
+    let highlighted = use_state(cx, || None::<usize>);
+    let query = use_state(cx, String::new);
+    let typed = use_ref(cx, String::new);
+    // Increased on every typed character, so only the latest one clears `typed` after the timeout
+    let keystroke = &*cx.use_hook(|| Rc::new(Cell::new(0u64)));
+    let button = use_element_handle(cx);
+    let id: &'a str = cx.use_hook(|| format!("nui-dropdown-{}", cx.scope_id().0));
+
+    let localization = use_localization(cx);
+    let placeholder = placeholder.unwrap_or(localization.messages.select());
+    let (search, no_results) = (
+        localization.messages.search(),
+        localization.messages.no_results(),
+    );
+
+    let selected_index = options
+        .iter()
+        .position(|(value, _)| Some(value) == selected.as_ref());
+    let matches = matching(options, query.get());
+
+    let select = move |index: usize| {
+        if let (Some(on_select), Some((value, _))) = (on_select, options.get(index)) {
+            on_select.call(value.clone());
+        }
+    };
+    let show = move || {
+        highlighted.set(selected_index.or(matching(options, "").first().copied()));
+        open.set(true);
+    };
+    let close = move |refocus: bool| {
+        open.set(false);
+        query.set(String::new());
+        typed.write_silent().clear();
+        if refocus {
+            to_owned![button];
+            cx.spawn(async move {
+                let _ = button.focus().await;
+            });
+        }
+    };
+
+    let type_character = move |character: &str, current: Option<usize>| {
+        let index = type_to_select(options, &mut typed.write_silent(), character, current);
+
+        let count = keystroke.get() + 1;
+        keystroke.set(count);
+        let (keystroke, typed) = (keystroke.clone(), typed.clone());
+        cx.spawn(async move {
+            Delay::new(TYPING_TIMEOUT).await;
+            if keystroke.get() == count {
+                typed.write_silent().clear();
+            }
+        });
+        index
+    };
+
+    // Shared by the button and the search field, which types the characters instead of selecting
+    let keydown = move |e: &KeyboardEvent, in_search: bool| {
+        let matches = matching(options, query.get());
+        let position = highlighted
+            .get()
+            .and_then(|i| matches.iter().position(|&m| m == i));
+        // Shortcuts like Ctrl+C are not typed
+        let typing = !e
+            .modifiers()
+            .intersects(Modifiers::CONTROL | Modifiers::META | Modifiers::ALT);
+
+        match (*open.get(), e.key()) {
+            (false, Key::ArrowDown | Key::ArrowUp | Key::Enter) => show(),
+            (false, Key::Character(c)) if c == " " => show(),
+            // Like a native select, typing selects without opening
+            (false, Key::Character(c)) if typing => {
+                if let Some(index) = type_character(&c, selected_index) {
+                    select(index);
+                }
+            }
+            (true, Key::ArrowDown) => {
+                let next = position.map_or(0, |p| (p + 1).min(matches.len().saturating_sub(1)));
+                highlighted.set(matches.get(next).copied());
+            }
+            (true, Key::ArrowUp) => {
+                let previous = position.map_or(0, |p| p.saturating_sub(1));
+                highlighted.set(matches.get(previous).copied());
+            }
+            (true, Key::Home) if !in_search => highlighted.set(matches.first().copied()),
+            (true, Key::End) if !in_search => highlighted.set(matches.last().copied()),
+            (true, Key::Enter) => {
+                if let Some(index) = position.map(|p| matches[p]) {
+                    select(index);
+                }
+                close(true);
+            }
+            (true, Key::Escape) => close(true),
+            (true, Key::Tab) => close(false),
+            (true, Key::Character(c)) if !in_search && c == " " && typed.read().is_empty() => {
+                if let Some(index) = position.map(|p| matches[p]) {
+                    select(index);
+                }
+                close(true);
+            }
+            (true, Key::Character(c)) if typing && !in_search => {
+                let current = *highlighted.get();
+                if let Some(index) = type_character(&c, current) {
+                    highlighted.set(Some(index));
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let (label, label_class) = match selected_index {
+        Some(index) => (options[index].1, class::dropdown_label),
+        None => (
+            placeholder,
+            constcat::concat!(class::dropdown_label, " ", class::dropdown_placeholder),
+        ),
+    };
+    // The native click of Enter and Space would toggle the list again, and the arrow keys scroll the page
+    let prevent_keys = match *open.get() {
+        true => "ArrowUp ArrowDown Home End Enter Space",
+        false => "ArrowUp ArrowDown Enter Space",
+    };
+    let active_descendant = highlighted
+        .get()
+        .filter(|_| *open.get())
+        .map(|i| cx.raw_text(format_args!("{id}-option-{i}")));
+
+    render_component! {
+        div {
+            $CLASS: "{class::dropdown}",
+            button {
+                class: "{class::dropdown_button}",
+                r#type: "button",
+                role: "combobox",
+                disabled: disabled.map_str(),
+                "aria-haspopup": "listbox",
+                "aria-expanded": open.get().to_str(),
+                "aria-controls": "{id}-listbox",
+                "aria-activedescendant": active_descendant,
+                "data-nui-prevent-keys": prevent_keys,
+                onclick: move |e: MouseEvent| {
+                    if *open.get() {
+                        close(false);
+                    } else {
+                        show();
+                    }
+                    if let Some(onclick) = onclick {
+                        onclick.call(e);
+                    }
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    keydown(&e, false);
+                    if let Some(onkeydown) = onkeydown {
+                        onkeydown.call(e);
+                    }
+                },
+                onmounted: move |e: MountedEvent| {
+                    button.set(e.clone());
+                    if let Some(onmounted) = onmounted {
+                        onmounted.call(e);
+                    }
+                },
+                $GLOBALS,
+                span { class: "{label_class}", label }
+                ChevronDown {}
+            }
+            if *open.get() {
+                rsx! {
+                    // Closes the popup when clicking outside of it
+                    div { class: "{class::dropdown_backdrop}", onclick: move |_| close(false) }
+                    div { class: "{class::dropdown_popup}",
+                        if *searchable {
+                            rsx! {
+                                input {
+                                    class: "{class::dropdown_search}",
+                                    r#type: "search",
+                                    placeholder: "{search}",
+                                    "aria-label": "{search}",
+                                    "aria-controls": "{id}-listbox",
+                                    "aria-activedescendant": active_descendant,
+                                    value: "{query}",
+                                    // Enter would submit the form
+                                    "data-nui-prevent-keys": "ArrowUp ArrowDown Enter",
+                                    oninput: move |e: Event<FormData>| {
+                                        highlighted.set(matching(options, &e.value).first().copied());
+                                        query.set(e.value.clone());
+                                    },
+                                    onkeydown: move |e: KeyboardEvent| keydown(&e, true),
+                                    onmounted: move |e: MountedEvent| {
+                                        cx.spawn(async move {
+                                            let _ = e.set_focus(true).await;
+                                        });
+                                    },
+                                }
+                            }
+                        }
+                        div {
+                            id: "{id}-listbox",
+                            class: "{class::dropdown_list}",
+                            role: "listbox",
+                            for &index in matches.iter() {
+                                div {
+                                    key: "{index}",
+                                    id: "{id}-option-{index}",
+                                    class: "{class::dropdown_option}",
+                                    role: "option",
+                                    "aria-selected": (Some(index) == selected_index).to_str(),
+                                    "data-highlighted": (Some(index) == *highlighted.get()).then_some("true"),
+                                    onmouseenter: move |_| highlighted.set(Some(index)),
+                                    onclick: move |_| {
+                                        select(index);
+                                        close(true);
+                                    },
+                                    options[index].1
+                                }
+                            }
+                            if matches.is_empty() {
+                                rsx! { div { class: "{class::dropdown_empty}", "{no_results}" } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Indices of the options whose label contains `query`, ignoring case.
+fn matching<T>(options: &[(T, &str)], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, label))| label.to_lowercase().contains(&query))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Finds the option to select after typing `character`, `typed` holds the previously typed ones.
+///
+/// Typing more characters refines the current match, typing one that doesn't match starts over
+/// and repeating the first one cycles through the options starting with it.
+fn type_to_select<T>(
+    options: &[(T, &str)],
+    typed: &mut String,
+    character: &str,
+    current: Option<usize>,
+) -> Option<usize> {
+    if options.is_empty() {
+        return None;
+    }
+    let character = character.to_lowercase();
+    typed.push_str(&character);
+
+    let find = |prefix: &str, skip: usize| {
+        let start = current.map_or(0, |i| i + skip);
+        (0..options.len())
+            .map(|i| (start + i) % options.len())
+            .find(|&i| options[i].1.to_lowercase().starts_with(prefix))
+    };
+
+    let skip = usize::from(typed.chars().count() == 1);
+    find(typed.as_str(), skip).or_else(|| {
+        *typed = character;
+        find(typed.as_str(), 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matching, type_to_select};
+
+    const OPTIONS: [((), &str); 5] = [
+        ((), "Banana"),
+        ((), "Apple"),
+        ((), "Apricot"),
+        ((), "avocado"),
+        ((), "Cherry"),
+    ];
+
+    #[test]
+    fn matches_ignoring_case() {
+        assert_eq!(matching(&OPTIONS, ""), [0, 1, 2, 3, 4]);
+        assert_eq!(matching(&OPTIONS, "AP"), [1, 2]);
+        assert_eq!(matching(&OPTIONS, "an"), [0]);
+        assert!(matching(&OPTIONS, "kiwi").is_empty());
+    }
+
+    #[test]
+    fn cycles() {
+        let mut typed = String::new();
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "a", None), Some(1));
+        // Repeating the first character moves to the next option starting with it
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "a", Some(1)), Some(2));
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "a", Some(2)), Some(3));
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "a", Some(3)), Some(1));
+        assert_eq!(typed, "a");
+    }
+
+    #[test]
+    fn refines() {
+        let mut typed = String::new();
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "A", None), Some(1));
+        // The current option still matches
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "p", Some(1)), Some(1));
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "r", Some(1)), Some(2));
+        assert_eq!(typed, "apr");
+    }
+
+    #[test]
+    fn restarts() {
+        let mut typed = String::from("ap");
+        // No option starts with "apc", so "c" is searched on its own
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "c", Some(1)), Some(4));
+        assert_eq!(typed, "c");
+        // Nothing starts with "z"
+        assert_eq!(type_to_select(&OPTIONS, &mut typed, "z", Some(4)), None);
+        assert_eq!(type_to_select::<()>(&[], &mut typed, "a", None), None);
+    }
+}
@@ -1,257 +1,215 @@
-use dioxus::prelude::*;
-use reusable::reusable;
+use crate::{class::{self, Classes}, ToStr};
+use dioxus::{
+    core::{Attribute, AttributeValue, IntoAttributeValue},
+    prelude::*,
+};
+use dioxus_nui_macros::nui_component;
 
 // Global Attributes & Events
-// Uses the [reusable](https://crates.io/crates/reusable) crate to add the global attributes to the built elements.
-#[allow(dead_code)]
-#[reusable(global_attributes)]
-#[derive(Props, PartialEq)]
-/// Struct representing global HTML attributes that can be applied to various HTML elements.
-pub(crate) struct GlobalAttributes<'a> {
-    /// Specifies a shortcut key to activate or focus an element.
-    #[props(into)]
-    accesskey: Option<&'a str>,
-    /// Controls whether and how text input is automatically capitalized as it is entered/edited by the user.
-    #[props(into)]
-    autocapitalize: Option<&'a str>,
-    /// Indicates whether an element should have input focus when the page loads.
-    #[props(into)]
-    autofocus: Option<&'a str>,
-    /// Specifies one or more class names for an element.
-    #[props(into)]
-    class: Option<&'a str>,
-    /// Indicates whether the content of an element can be edited by the user.
-    #[props(into)]
-    contenteditable: Option<&'a str>,
-    /// Used to store custom data private to the page or application.
-    #[props(into)]
-    data: Option<&'a str>,
-    /// Specifies the text direction for the content in an element.
-    #[props(into)]
-    dir: Option<&'a str>,
-    /// Specifies whether an element is draggable or not.
-    #[props(into)]
-    draggable: Option<&'a str>,
-    /// Provides a hint for the type of user action that is expected when an element receives focus.
-    #[props(into)]
-    enterkeyhint: Option<&'a str>,
-    /// Indicates whether the element is relevant.
-    #[props(into)]
-    hidden: Option<&'a str>,
-    /// Defines a unique identifier for an element.
-    #[props(into)]
-    id: Option<&'a str>,
-    /// Specifies a hint to the browser for which virtual keyboard to display.
-    #[props(into)]
-    inputmode: Option<&'a str>,
-    /// Defines the type of the element.
-    #[props(into)]
-    is: Option<&'a str>,
-    /// Specifies a URL which designates a page which describes the offering.
-    #[props(into)]
-    itemid: Option<&'a str>,
-    /// Associates an element with one or more items within an HTML document.
-    #[props(into)]
-    itemprop: Option<&'a str>,
-    /// Specifies additional items to include in the accessibility tree.
-    #[props(into)]
-    itemref: Option<&'a str>,
-    /// Specifies the URL(s) of the vocabulary that defines the item(s) for an element.
-    #[props(into)]
-    itemtype: Option<&'a str>,
-    /// Specifies the primary language for the element's contents.
-    #[props(into)]
-    lang: Option<&'a str>,
-    /// Provides a mechanism to enable the server to declare a policy for the page to report back to it when a nonce is used.
-    #[props(into)]
-    nonce: Option<&'a str>,
-    /// Assigns a part name to an element, so that it can be used from the element's CSS, JavaScript or other parts of the page.
-    #[props(into)]
-    part: Option<&'a str>,
-    /// Defines a role name for an element.
-    #[props(into)]
-    role: Option<&'a str>,
-    /// Assigns a slot name to an element.
-    #[props(into)]
-    slot: Option<&'a str>,
-    /// Specifies whether a user can edit the content of an element.
-    #[props(into)]
-    spellcheck: Option<&'a str>,
-    /// Specifies an inline CSS style for an element.
-    #[props(into)]
-    style: Option<&'a str>,
-    /// Specifies the tabbing order for an element.
-    #[props(into)]
-    tabindex: Option<&'a str>,
-    /// Defines a title for the element.
-    #[props(into)]
-    title: Option<&'a str>,
-    /// Specifies whether the element's attribute values and the values of its Text node children are to be translated when the page is localized.
-    #[props(into)]
-    translate: Option<&'a str>,
+// The fields are defined in `dioxus-nui-macros` and added to every component with `#[nui_component]`,
+// `render_component!` forwards them to the element with `$GLOBALS`.
+#[nui_component(form, media)]
+#[derive(Props)]
+/// Properties accepted by every NUI component.
+///
+/// Contains the [Global Attributes and Global Events](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes),
+/// along with the NUI specific `class` and `accent`.
+///
+/// Attributes are typed: `hidden: false` does not render the attribute, `tabindex` takes an `i32`
+/// and `dir`, `autocapitalize`, `enterkeyhint` and `inputmode` take an enum ([`Dir`], [`AutoCapitalize`], ...).
+///
+/// Form events (`oninput`, `onchange`, ...) are only accepted by form components like [`Input`](crate::Input),
+/// and media events (`onplay`, `onload`, ...) by media components.
+///
+/// It is not used directly, it only documents the properties added by [`nui_component`](crate::nui_component).
+pub struct GlobalProps<'a> {}
+
+/// Builds the `data-*` and extra attributes of a component, spread into the element by `$GLOBALS`.
+pub fn extra_attributes<'a>(
+    cx: &'a ScopeState,
+    data: &'a [(&'a str, &'a str)],
+    attributes: &'a [(&'a str, &'a str)],
+) -> Vec<Attribute<'a>> {
+    let data = data.iter().map(|(name, value)| {
+        let name = cx.bump().alloc_str(&format!("data-{name}"));
+        Attribute::new(name, AttributeValue::Text(value), None, false)
+    });
+    let attributes = attributes
+        .iter()
+        .map(|(name, value)| Attribute::new(name, AttributeValue::Text(value), None, false));
+
+    data.chain(attributes).collect()
+}
 
-    // SPECIFIC TO NUI
-    /// Sets accent, will work with most elements.
-    #[props(default)]
+/// Builds the class of a component, used by `$CLASS`.
+///
+/// `base` are the classes of the component, followed by `accent` and the `class` supplied by the user.
+pub fn merge_classes<'a>(
+    cx: &'a ScopeState,
+    base: std::fmt::Arguments,
     accent: bool,
+    class: &Option<Classes>,
+) -> &'a str {
+    let base = cx.raw_text(base);
+    let classes = Classes::from(base)
+        .when(accent, class::accent)
+        .merge(class.as_ref());
+
+    cx.raw_text(format_args!("{classes}"))
 }
 
-#[allow(dead_code)]
-#[reusable(global_events)]
-#[derive(Props)]
-/// A struct representing global event handlers that can be used with various HTML elements.
-pub(crate) struct GlobalEvents<'a> {
-    /// Triggers when a media playback is aborted.
-    #[props(default)]
-    onabort: EventHandler<'a, MediaEvent>,
-    /// Triggers when an element loses focus.
-    #[props(default)]
-    onblur: EventHandler<'a, FocusEvent>,
-    /// Triggers when the value of a form element is changed.
-    #[props(default)]
-    onchange: EventHandler<'a, FormEvent>,
-    /// Triggers when an element is clicked.
-    #[props(default)]
-    onclick: EventHandler<'a, MouseEvent>,
-    /// Triggers when the context menu is triggered by right-clicking on an element.
-    #[props(default)]
-    oncontextmenu: EventHandler<'a, MouseEvent>,
-    /// Triggers when an element is double-clicked.
-    #[props(default)]
-    ondblclick: EventHandler<'a, MouseEvent>,
-    /// Triggers when an element is being dragged.
-    #[props(default)]
-    ondrag: EventHandler<'a, DragEvent>,
-    /// Triggers when the dragging of an element is finished.
-    #[props(default)]
-    ondragend: EventHandler<'a, DragEvent>,
-    /// Triggers when a dragged element enters a drop target.
-    #[props(default)]
-    ondragenter: EventHandler<'a, DragEvent>,
-    /// Triggers when a dragged element leaves a drop target.
-    #[props(default)]
-    ondragleave: EventHandler<'a, DragEvent>,
-    /// Triggers when an element is being dragged over a drop target.
-    #[props(default)]
-    ondragover: EventHandler<'a, DragEvent>,
-    /// Triggers when the dragging of an element starts.
-    #[props(default)]
-    ondragstart: EventHandler<'a, DragEvent>,
-    /// Triggers when a dragged element is dropped on a drop target.
-    #[props(default)]
-    ondrop: EventHandler<'a, DragEvent>,
-    /// Triggers when the duration of a media element is changed.
-    #[props(default)]
-    ondurationchange: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element is emptied (e.g. via the "pause" button).
-    #[props(default)]
-    onemptied: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element has ended playback.
-    #[props(default)]
-    onended: EventHandler<'a, MediaEvent>,
-    /// Triggers when an error occurs while loading an image.
-    #[props(default)]
-    onerror: EventHandler<'a, ImageEvent>,
-    /// Triggers when an element receives focus.
-    #[props(default)]
-    onfocus: EventHandler<'a, FocusEvent>,
-    /// Triggers when the value of a form element is being changed.
-    #[props(default)]
-    oninput: EventHandler<'a, FormEvent>,
-    /// Triggers when a form element's value is invalid.
-    #[props(default)]
-    oninvalid: EventHandler<'a, FormEvent>,
-    /// Triggers when a key is pressed down while the element is in focus.
-    #[props(default)]
-    onkeydown: EventHandler<'a, KeyboardEvent>,
-    /// Triggers when a key is pressed while the element is in focus.
-    #[props(default)]
-    onkeypress: EventHandler<'a, KeyboardEvent>,
-    /// Triggers when a key is released while the element is in focus.
-    #[props(default)]
-    onkeyup: EventHandler<'a, KeyboardEvent>,
-    /// Triggers when an image is finished loading.
-    #[props(default)]
-    onload: EventHandler<'a, ImageEvent>,
-    /// Triggers when the media data is loaded and ready to be played.
-    #[props(default)]
-    onloadeddata: EventHandler<'a, MediaEvent>,
-    /// Triggers when the metadata (e.g. length) of a media element is loaded.
-    #[props(default)]
-    onloadedmetadata: EventHandler<'a, MediaEvent>,
-    /// Triggers when the loading of a media element starts.
-    #[props(default)]
-    onloadstart: EventHandler<'a, MediaEvent>,
-    /// Triggers when a mouse button is pressed down on an element.
-    #[props(default)]
-    onmousedown: EventHandler<'a, MouseEvent>,
-    /// Triggers when the mouse pointer enters an element.
-    #[props(default)]
-    onmouseenter: EventHandler<'a, MouseEvent>,
-    /// Triggers when the mouse pointer leaves an element.
-    #[props(default)]
-    onmouseleave: EventHandler<'a, MouseEvent>,
-    /// Triggers when the mouse pointer is moving over an element.
-    #[props(default)]
-    onmousemove: EventHandler<'a, MouseEvent>,
-    /// Triggers when the mouse pointer leaves an element, or one of its child elements, and enters another element.
-    #[props(default)]
-    onmouseout: EventHandler<'a, MouseEvent>,
-    /// Triggers when the mouse pointer enters an element, or one of its child elements, from another element.
-    #[props(default)]
-    onmouseover: EventHandler<'a, MouseEvent>,
-    /// Triggers when a mouse button is released on an element.
-    #[props(default)]
-    onmouseup: EventHandler<'a, MouseEvent>,
-    /// Triggers when a media element is paused.
-    #[props(default)]
-    onpause: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element starts playing.
-    #[props(default)]
-    onplay: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element has started playing.
-    #[props(default)]
-    onplaying: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element is progressing through its playback.
-    #[props(default)]
-    onprogress: EventHandler<'a, MediaEvent>,
-    /// Triggers when the playback rate of a media element is changed.
-    #[props(default)]
-    onratechange: EventHandler<'a, MediaEvent>,
-    /// Triggers when a form element is reset.
-    #[props(default)]
-    onreset: EventHandler<'a, FormEvent>,
-    /// Triggers when an element is being scrolled.
-    #[props(default)]
-    onscroll: EventHandler<'a, ScrollEvent>,
-    /// Triggers when a media element has finished seeking to a new position.
-    #[props(default)]
-    onseeked: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element is seeking a new position.
-    #[props(default)]
-    onseeking: EventHandler<'a, MediaEvent>,
-    /// Triggers when the text within a textarea or text input is selected by the user.
-    #[props(default)]
-    onselect: EventHandler<'a, SelectionEvent>,
-    /// Triggers when a media element is trying to load but eventually stalling.
-    #[props(default)]
-    onstalled: EventHandler<'a, MediaEvent>,
-    /// Triggers when a form is submitted.
-    #[props(default)]
-    onsubmit: EventHandler<'a, FormEvent>,
-    /// Triggers when the loading of a media element is suspended.
-    #[props(default)]
-    onsuspend: EventHandler<'a, MediaEvent>,
-    /// Triggers when the current playback position of a media element is updated.
-    #[props(default)]
-    ontimeupdate: EventHandler<'a, MediaEvent>,
-    /// Triggers when a checkbox element is toggled between on and off states.
-    #[props(default)]
-    ontoggle: EventHandler<'a, ToggleEvent>,
-    /// Triggers when the volume of a media element is changed.
-    #[props(default)]
-    onvolumechange: EventHandler<'a, MediaEvent>,
-    /// Triggers when a media element is waiting for data to load.
-    #[props(default)]
-    onwaiting: EventHandler<'a, MediaEvent>,
+/// Creates the listener for a global event, only if its `handler` was supplied.
+///
+/// Used by `$GLOBALS`, avoids registering every global event on every element.
+pub fn listener<'a, T: 'static>(
+    cx: &'a ScopeState,
+    name: &'static str,
+    handler: &'a Option<EventHandler<'a, Event<T>>>,
+) -> Option<Attribute<'a>> {
+    let handler = handler.as_ref()?;
+    let listener = cx.listener(move |e: Event<T>| handler.call(e));
+
+    Some(Attribute::new(name, listener, None, false))
+}
+
+/// Creates a global attribute, only if its `value` was set.
+pub fn attribute<'a>(
+    cx: &'a ScopeState,
+    name: &'static str,
+    value: Option<impl IntoAttributeValue<'a>>,
+) -> Option<Attribute<'a>> {
+    Some(Attribute::new(name, value?.into_value(cx.bump()), None, false))
+}
+
+/// Renders the global attributes and events supplied to a component.
+///
+/// Implemented by [`nui_component`](crate::nui_component) for the attributes and events the component accepts, used by `$GLOBALS`.
+pub trait GlobalAttributes<'a> {
+    /// Adds every attribute that was set and a listener for every supplied event to `attributes`,
+    /// except for the ones in `skip`.
+    fn globals(&'a self, cx: &'a ScopeState, skip: &[&str], attributes: &mut Vec<Attribute<'a>>);
+}
+
+/// Renders a boolean attribute (like `hidden`), which is only present if `true`.
+pub fn flag(value: Option<bool>) -> Option<&'static str> {
+    value.and_then(|value| value.then_some("true"))
+}
+
+/// Renders an attribute that uses `"yes"` and `"no"` instead of `"true"` and `"false"` (like `translate`).
+pub fn yes_no(value: Option<bool>) -> Option<&'static str> {
+    value.map(|value| if value { "yes" } else { "no" })
+}
+
+/// Text direction, value of the `dir` attribute.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Dir {
+    /// Left to right, for languages like English.
+    Ltr,
+    /// Right to left, for languages like Arabic.
+    Rtl,
+    /// Lets the browser decide based on the content.
+    Auto,
+}
+
+impl ToStr<'static> for Dir {
+    fn to_str(&self) -> &'static str {
+        match self {
+            Dir::Ltr => "ltr",
+            Dir::Rtl => "rtl",
+            Dir::Auto => "auto",
+        }
+    }
+}
+
+/// How text input is automatically capitalized, value of the `autocapitalize` attribute.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AutoCapitalize {
+    /// No automatic capitalization.
+    None,
+    /// First letter of each sentence.
+    Sentences,
+    /// First letter of each word.
+    Words,
+    /// All letters.
+    Characters,
+}
+
+impl ToStr<'static> for AutoCapitalize {
+    fn to_str(&self) -> &'static str {
+        match self {
+            AutoCapitalize::None => "none",
+            AutoCapitalize::Sentences => "sentences",
+            AutoCapitalize::Words => "words",
+            AutoCapitalize::Characters => "characters",
+        }
+    }
+}
+
+/// Label of the enter key on virtual keyboards, value of the `enterkeyhint` attribute.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum EnterKeyHint {
+    /// Inserts a new line.
+    Enter,
+    /// Nothing more to input, closes the virtual keyboard.
+    Done,
+    /// Takes the user to the target of the typed text, like a URL.
+    Go,
+    /// Moves to the next field.
+    Next,
+    /// Moves to the previous field.
+    Previous,
+    /// Searches for the typed text.
+    Search,
+    /// Sends the typed text, like a message.
+    Send,
+}
+
+impl ToStr<'static> for EnterKeyHint {
+    fn to_str(&self) -> &'static str {
+        match self {
+            EnterKeyHint::Enter => "enter",
+            EnterKeyHint::Done => "done",
+            EnterKeyHint::Go => "go",
+            EnterKeyHint::Next => "next",
+            EnterKeyHint::Previous => "previous",
+            EnterKeyHint::Search => "search",
+            EnterKeyHint::Send => "send",
+        }
+    }
+}
+
+/// Virtual keyboard to display, value of the `inputmode` attribute.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum InputMode {
+    /// No virtual keyboard, for elements that implement their own.
+    None,
+    /// Standard keyboard for the user's locale.
+    Text,
+    /// Digits and the decimal separator of the user's locale.
+    Decimal,
+    /// Digits only.
+    Numeric,
+    /// Digits, `*` and `#`, for telephone numbers.
+    Tel,
+    /// Keyboard optimized for searching, the enter key may be labeled "Search".
+    Search,
+    /// Keyboard optimized for email addresses, with `@` and `.`.
+    Email,
+    /// Keyboard optimized for URLs, with `/` and `.`.
+    Url,
+}
+
+impl ToStr<'static> for InputMode {
+    fn to_str(&self) -> &'static str {
+        match self {
+            InputMode::None => "none",
+            InputMode::Text => "text",
+            InputMode::Decimal => "decimal",
+            InputMode::Numeric => "numeric",
+            InputMode::Tel => "tel",
+            InputMode::Search => "search",
+            InputMode::Email => "email",
+            InputMode::Url => "url",
+        }
+    }
 }
@@ -0,0 +1,99 @@
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// Handle to the element rendered by a NUI component, to focus it or scroll it into view.
+///
+/// Created with [`use_element_handle`] and filled by the `onmounted` event of the component.
+///
+/// # Example
+/// ```
+/// let input = use_element_handle(cx);
+///
+/// render! {
+///     Input { onmounted: move |e| input.set(e), on_change: |_| {} }
+///     Button {
+///         onclick: move |_| {
+///             to_owned![input];
+///             cx.spawn(async move { let _ = input.focus().await; });
+///         },
+///         "Edit"
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ElementHandle(Rc<RefCell<Option<Rc<MountedData>>>>);
+
+impl ElementHandle {
+    /// Stores the element of a `onmounted` event.
+    pub fn set(&self, event: MountedEvent) {
+        *self.0.borrow_mut() = Some(event.inner().clone());
+    }
+
+    /// Returns the element, `None` if it hasn't been mounted yet.
+    pub fn get(&self) -> Option<Rc<MountedData>> {
+        self.0.borrow().clone()
+    }
+
+    /// Returns `true` if the element has been mounted.
+    pub fn is_mounted(&self) -> bool {
+        self.0.borrow().is_some()
+    }
+
+    /// Focuses the element.
+    pub async fn focus(&self) -> Result<(), HandleError> {
+        self.element()?.set_focus(true).await?;
+        Ok(())
+    }
+
+    /// Removes the focus from the element.
+    pub async fn blur(&self) -> Result<(), HandleError> {
+        self.element()?.set_focus(false).await?;
+        Ok(())
+    }
+
+    /// Scrolls the element into view, smoothly.
+    pub async fn scroll_into_view(&self) -> Result<(), HandleError> {
+        self.scroll_into_view_with(ScrollBehavior::Smooth).await
+    }
+
+    /// Scrolls the element into view with the given `behavior`.
+    pub async fn scroll_into_view_with(&self, behavior: ScrollBehavior) -> Result<(), HandleError> {
+        self.element()?.scroll_to(behavior).await?;
+        Ok(())
+    }
+
+    fn element(&self) -> Result<Rc<MountedData>, HandleError> {
+        self.get().ok_or(HandleError::Unmounted)
+    }
+}
+
+/// Creates an [`ElementHandle`] that persists between renders.
+pub fn use_element_handle(cx: &ScopeState) -> &ElementHandle {
+    cx.use_hook(ElementHandle::default)
+}
+
+/// Error returned by the methods of [`ElementHandle`].
+#[derive(Debug)]
+pub enum HandleError {
+    /// The element hasn't been mounted yet, or `onmounted` wasn't forwarded to the handle.
+    Unmounted,
+    /// The renderer failed or doesn't support the operation.
+    Mounted(MountedError),
+}
+
+impl From<MountedError> for HandleError {
+    fn from(error: MountedError) -> Self {
+        HandleError::Mounted(error)
+    }
+}
+
+impl std::fmt::Display for HandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandleError::Unmounted => f.write_str("the element has not been mounted"),
+            HandleError::Mounted(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for HandleError {}
@@ -1,9 +1,8 @@
-use crate::{class, init::CheckIfUninit, Align};
+use crate::{class, Align};
 use dioxus::prelude::*;
-use dioxus_nui_macros::render_component;
-use reusable::reuse;
+use dioxus_nui_macros::{nui_component, render_component};
 
-#[reuse(global_attributes, global_events)]
+#[nui_component]
 #[derive(Props)]
 pub struct HeaderProps<'a> {
     #[props(default)]
@@ -15,3 +15,87 @@ pub(crate) fn Square(cx: Scope) -> Element {
         }
     }
 }
+
+#[inline_props]
+pub(crate) fn ChevronDown(cx: Scope) -> Element {
+    render! {
+        svg {
+            width: 16,
+            height: 16,
+            view_box: "0 0 16 16",
+            fill: "none",
+
+            path {
+                d: "M3.5 6L8 10.5L12.5 6", stroke: "currentColor", stroke_width: 2, stroke_linecap: "round", stroke_linejoin: "round"
+            }
+        }
+    }
+}
+
+#[inline_props]
+pub(crate) fn Minus(cx: Scope) -> Element {
+    render! {
+        svg {
+            width: 16,
+            height: 16,
+            view_box: "0 0 16 16",
+            fill: "none",
+
+            path {
+                d: "M3 8H13", stroke: "currentColor", stroke_width: 2, stroke_linecap: "round"
+            }
+        }
+    }
+}
+
+#[inline_props]
+pub(crate) fn Plus(cx: Scope) -> Element {
+    render! {
+        svg {
+            width: 16,
+            height: 16,
+            view_box: "0 0 16 16",
+            fill: "none",
+
+            path {
+                d: "M3 8H13M8 3V13", stroke: "currentColor", stroke_width: 2, stroke_linecap: "round"
+            }
+        }
+    }
+}
+
+#[inline_props]
+pub(crate) fn Eye(cx: Scope) -> Element {
+    render! {
+        svg {
+            width: 16,
+            height: 16,
+            view_box: "0 0 16 16",
+            fill: "none",
+
+            path {
+                d: "M1.5 8C3 5 5.3 3.5 8 3.5S13 5 14.5 8C13 11 10.7 12.5 8 12.5S3 11 1.5 8Z", stroke: "currentColor", stroke_width: 1.5, stroke_linejoin: "round"
+            }
+            circle { cx: 8, cy: 8, r: 2, fill: "currentColor" }
+        }
+    }
+}
+
+#[inline_props]
+pub(crate) fn EyeOff(cx: Scope) -> Element {
+    render! {
+        svg {
+            width: 16,
+            height: 16,
+            view_box: "0 0 16 16",
+            fill: "none",
+
+            path {
+                d: "M1.5 8C3 5 5.3 3.5 8 3.5S13 5 14.5 8C13 11 10.7 12.5 8 12.5S3 11 1.5 8Z", stroke: "currentColor", stroke_width: 1.5, stroke_linejoin: "round"
+            }
+            path {
+                d: "M2.5 2.5L13.5 13.5", stroke: "currentColor", stroke_width: 1.5, stroke_linecap: "round"
+            }
+        }
+    }
+}
@@ -1,38 +1,162 @@
-use crate::Theme;
+use crate::{
+    diagnostics::{misuse, use_first_render},
+    English, Locale, Localization, Messages, Theme,
+};
 use dioxus::prelude::*;
+use std::{cell::Cell, rc::Rc};
 
-/// Initializes NUI styling.
+#[derive(Props)]
+pub struct InitNuiProps {
+    /// Theme used by the components, see [`Theme`] for the default.
+    theme: Option<Theme>,
+    /// How numbers and dates are written, `Locale::EN_US` by default.
+    locale: Option<Locale>,
+    /// Strings shown by the components, [`English`] by default.
+    messages: Option<Rc<dyn Messages>>,
+}
+
+impl PartialEq for InitNuiProps {
+    fn eq(&self, other: &Self) -> bool {
+        let same_messages = match (&self.messages, &other.messages) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        self.theme == other.theme && self.locale == other.locale && same_messages
+    }
+}
+
+/// Initializes NUI styling and localization.
 ///
 /// Must be used before any element imported from this crate.
 ///
 /// If not used, it'll be initialized with a default value (depending on the platform), see [`Theme`](theme) for more information.
-#[inline_props]
-pub fn InitNui(cx: Scope, theme: Option<Theme>) -> Element {
-    INITIALIZED.store(true, std::sync::atomic::Ordering::Release);
+///
+/// The `locale` and `messages` are available to every component through [`use_localization`](crate::use_localization).
+///
+/// It also installs a listener that prevents the default action of the keys handled by the components,
+/// like the arrow keys moving the caret of a [`SpinButton`](crate::SpinButton).
+pub fn InitNui(cx: Scope<InitNuiProps>) -> Element {
+    let theme = cx.props.theme.unwrap_or_default();
+
+    if use_first_render(cx) {
+        if cx.consume_context::<NuiContext>().is_some() {
+            misuse!(
+                "InitNui",
+                "rendered more than once (or after a component initialized NUI automatically), the last one rendered overrides the others"
+            );
+        }
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!(target: "dioxus_nui", ?theme, locale = cx.props.locale.unwrap_or_default().tag, "initialized NUI");
+    }
+
+    // Root context, so every component in this VirtualDom knows NUI is initialized
+    cx.provide_root_context(NuiContext { theme });
+    cx.provide_root_context(Localization {
+        locale: cx.props.locale.unwrap_or_default(),
+        messages: cx
+            .props
+            .messages
+            .clone()
+            .unwrap_or_else(|| Rc::new(English)),
+    });
+
+    let sink = cx.consume_context::<StylesheetSink>();
+    // Not needed when rendering on the server, where there are no keys to handle
+    cx.use_hook(|| {
+        let installed = cx
+            .consume_context::<Rc<dyn EvalProvider>>()
+            .map_or(false, |eval| eval.new_evaluator(PREVENT_KEYS.to_string()).is_ok());
+        if !installed && sink.is_none() {
+            misuse!(
+                "InitNui",
+                "the renderer can't run scripts, the keys handled by the components (like the arrow keys of a `SpinButton`) will also scroll the page or move the caret"
+            );
+        }
+    });
+
+    // When rendering on the server the stylesheet is placed in <head> instead
+    if let Some(sink) = sink {
+        sink.0.set(Some(theme));
+        return None;
+    }
 
     render! {
-        style { display: "none", theme.unwrap_or_default().to_style() }
+        style { display: "none", theme.stylesheet() }
     }
 }
 
-/// If `true` NUI has been initialized with a theme.
+/// Prevents the default action of the keys listed in the `data-nui-prevent-keys` attribute of an element,
+/// separated by spaces (`Space` for the space bar).
+///
+/// Dioxus can only prevent the default action of every key, which would also block typing in an entry.
+/// Installed once, even if `InitNui` is rendered again.
+const PREVENT_KEYS: &str = r#"
+if (!window.nuiPreventKeys) {
+    window.nuiPreventKeys = (event) => {
+        const keys = event.target.getAttribute && event.target.getAttribute("data-nui-prevent-keys");
+        const key = event.key === " " ? "Space" : event.key;
+        if (keys && keys.split(" ").includes(key)) {
+            event.preventDefault();
+        }
+    };
+    document.addEventListener("keydown", window.nuiPreventKeys, true);
+    document.addEventListener("keyup", window.nuiPreventKeys, true);
+}
+"#;
+
+/// State of NUI, available to every component after [`InitNui`](InitNui) is rendered.
+///
+/// # Example
+/// ```
+/// let theme = cx.consume_context::<NuiContext>().map(|nui| nui.theme);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct NuiContext {
+    /// Theme used by the app.
+    pub theme: Theme,
+}
+
+/// Receives the theme instead of rendering the stylesheet inline.
 ///
-/// Atomic because dioxus can start CheckIfUninit from different Tokio threads
-static INITIALIZED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+/// Provided as a root context when rendering on the server, see [`ssr`](crate::ssr).
+#[derive(Clone, Default)]
+pub(crate) struct StylesheetSink(pub(crate) Rc<Cell<Option<Theme>>>);
+
+/// Provided once the use of a component without `InitNui` has been reported.
+#[cfg(not(feature = "auto-init"))]
+#[derive(Clone, Copy)]
+struct UninitReported;
 
 /// Checks if NUI is initialized.
 ///
 /// If not, it returns an [`InitNui`](InitNui) element.
-pub(crate) fn CheckIfUninit(cx: Scope) -> Element {
+///
+/// Added by [`render_component!`](crate::render_component) to every component, not meant to be used directly.
+#[doc(hidden)]
+pub fn CheckIfUninit(cx: Scope) -> Element {
     #[cfg(not(feature = "auto-init"))]
-    return None;
+    {
+        // Reported once per app, not for every component
+        if use_first_render(cx)
+            && cx.consume_context::<NuiContext>().is_none()
+            && cx.consume_context::<UninitReported>().is_none()
+        {
+            cx.provide_root_context(UninitReported);
+            misuse!(
+                "InitNui",
+                "a component was rendered before `InitNui` with the `auto-init` feature disabled, it's not styled and the keys it handles are not prevented"
+            );
+        }
+        return None;
+    }
 
-    if INITIALIZED.load(std::sync::atomic::Ordering::Acquire) {
+    // The context is per VirtualDom, so nothing is shared between apps (or server requests)
+    if cx.consume_context::<NuiContext>().is_some() {
         return None;
     }
 
-    #[cfg(debug_assertions)]
-    println!("NUI: Initialized NUI");
+    #[cfg(feature = "tracing")]
+    ::tracing::debug!(target: "dioxus_nui", "no `InitNui` found, initializing NUI with the default theme");
 
     render! { InitNui {} }
 }
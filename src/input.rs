@@ -1,20 +1,39 @@
-use crate::{ToStr, class};
+use crate::{
+    class,
+    diagnostics::{misuse, use_first_render},
+    global::GlobalAttributes,
+    Binding, MapStr, ToStr,
+};
 
 use std::fmt::Display;
 
 use dioxus::prelude::*;
-use dioxus_nui_macros::render_component;
-use reusable::reuse;
+use dioxus_nui_macros::{nui_component, render_component};
 
-#[reuse(global_events, global_attributes)]
+#[nui_component(form)]
 #[derive(Props)]
 pub struct InputProps<'a> {
-    #[props(default)]
-    input_type: InputType,
-    on_change: EventHandler<'a, String>,
+    /// Type of the input.
+    ///
+    /// `InputType::Text` by default, or the type of the value bound with `bind`.
+    #[props(into)]
+    input_type: Option<InputType>,
+    /// Called with the raw value of the input every time it changes.
+    on_change: Option<EventHandler<'a, String>>,
     value: Option<&'a str>,
     name: Option<&'a str>,
     label: Option<&'a str>,
+
+    /// Binds a `&UseState<T>` to the input, see [`Binding`].
+    ///
+    /// Takes precedence over `value`.
+    #[props(into)]
+    bind: Option<Binding<'a>>,
+
+    /// Global attributes and events of a component built on `Input`, forwarded to the input
+    /// except for the ones it sets itself (by property or HTML name).
+    #[doc(hidden)]
+    globals: Option<(&'a dyn GlobalAttributes<'a>, &'a [&'a str])>,
 }
 
 pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element<'a> {
@@ -24,20 +43,58 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element<'a> {
         on_change,
         value,
         label,
+        bind,
+        globals,
+        oninput,
         ..
     } = cx.props;
 
+    let input_type = (*input_type)
+        .or(bind.as_ref().map(Binding::input_type))
+        .unwrap_or_default();
+
+    if use_first_render(cx) && input_type == InputType::Button && label.is_some() {
+        misuse!(
+            "Input",
+            "`label` is shown next to the button, use `value` to set its text (or the `Button` component)"
+        );
+    }
+
+    // Uncontrolled inputs keep the value they had on the first render
+    let initial = cx.use_hook(|| bind.as_ref().map(|b| (b.value().to_owned(), b.checked())));
+    let (value, checked) = match (bind, initial) {
+        (Some(bind), _) if bind.is_controlled() => (Some(bind.value()), bind.checked()),
+        (Some(_), Some((value, checked))) => (Some(value.as_str()), *checked),
+        _ => (*value, None),
+    };
+
     render_component! {
         label {
             class: "{class::label}",
             *label,
             input {
                 $CLASS: "{input_type.to_style()}",
-                oninput: move |v: Event<FormData>| on_change.call(v.value.clone()),
+                oninput: move |v: Event<FormData>| {
+                    if let Some(bind) = bind {
+                        bind.set(&v.value);
+                    }
+                    if let Some(on_change) = on_change {
+                        on_change.call(v.value.clone());
+                    }
+                    if let Some(oninput) = oninput {
+                        oninput.call(v);
+                    }
+                },
                 name: *name,
                 r#type: "{input_type}",
-                value: *value,
-                $GLOBALS,
+                value: value,
+                checked: checked.map_str(),
+                $GLOBALS: globals.map(|(props, skip)| {
+                    let mut attributes = Vec::new();
+                    props.globals(cx, skip, &mut attributes);
+                    attributes
+                })
+                .unwrap_or_default(),
             }
         }
     }
@@ -46,7 +103,7 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element<'a> {
 /// `type` attribute of the [`Input`](crate::Input) component.
 ///
 /// For more information see https://www.w3schools.com/tags/tag_input.asp.
-#[derive(Default)]
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
 pub enum InputType {
     /// Sets the [`Input`] component type as a button.
     ///
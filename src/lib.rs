@@ -1,13 +1,34 @@
 #![allow(non_snake_case)]
 
+// Allows the macros to refer to `::dioxus_nui` from inside this crate too.
+extern crate self as dioxus_nui;
+
+mod accordion;
+mod aria;
+mod autocomplete;
+mod bind;
+mod diagnostics;
+mod dropdown;
 mod global;
 mod button;
+mod checkbox;
+mod handle;
 pub mod class;
 mod header;
 mod icons;
 mod init;
 mod input;
 mod list;
+mod locale;
+mod password;
+mod radio;
+mod roving;
+mod slider;
+mod spin;
+#[cfg(feature = "ssr")]
+pub mod ssr;
+mod switch;
+mod textarea;
 
 /// Theme that NUI will use.
 ///
@@ -34,15 +55,20 @@ pub enum Theme {
 }
 
 impl Theme {
-    /// Returns CSS style corresponding to the current theme
-    fn to_style(self) -> &'static str {
+    /// Returns the CSS stylesheet of the theme.
+    ///
+    /// Useful to link it or place it in `<head>` when rendering on the server, see [`ssr`](crate::ssr).
+    pub fn stylesheet(self) -> &'static str {
+        // The other themes restyle the components on top of the Adwaita stylesheet
+        const ADWAITA: &str = dioxus_nui_macros::include_css_safe!("styles/adwaita.css");
+
         match self {
-            // TODO: Temporary measure to test on other platforms until other styles are made
-            _ | Theme::Adwaita => dioxus_nui_macros::include_css_safe!("styles/adwaita.css"),
-            Theme::Qt => todo!(),
-            Theme::Windows10 => todo!(),
-            Theme::Windows11 => todo!(),
-            Theme::Macos => todo!(),
+            Theme::Adwaita => ADWAITA,
+            Theme::Qt => constcat::concat!(ADWAITA, dioxus_nui_macros::include_css_safe!("styles/breeze.css")),
+            Theme::Windows10 | Theme::Windows11 => {
+                constcat::concat!(ADWAITA, dioxus_nui_macros::include_css_safe!("styles/fluent.css"))
+            }
+            Theme::Macos => constcat::concat!(ADWAITA, dioxus_nui_macros::include_css_safe!("styles/aqua.css")),
         }
     }
 }
@@ -106,13 +132,24 @@ impl std::fmt::Display for Align {
     }
 }
 
+/// Direction in which the items of a component are laid out.
+///
+/// `Orientation::Horizontal` by default.
+#[derive(Default, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
 // UTILS
 /// Implements the `to_str` method.
 ///
 /// Converts value to a `&str`.
 ///
 /// Same as `to_string` but with `&str` instead.
-trait ToStr<'a> {
+#[doc(hidden)]
+pub trait ToStr<'a> {
     /// Converts value to a `&str`.
     ///
     /// Same as `to_string` but with `&str` instead.
@@ -141,7 +178,8 @@ impl<'a> ToStr<'a> for &'a str {
     }
 }
 
-trait MapStr<'a> {
+#[doc(hidden)]
+pub trait MapStr<'a> {
     type StrOut;
     /// Converts contained value to `&str`.
     ///
@@ -163,36 +201,38 @@ impl<'a, T: ToStr<'a>, E> MapStr<'a> for Result<T, E> {
     }
 }
 
-trait UnwrapStr<'a> {
-    /// Returns value contained as `&str` if "happy path" or `""` if it's `Result::Err` or `Option::None`.
-    fn unwrap_as_str(&'a self) -> &'a str;
-}
-
-impl<'a, T: ToStr<'a>> UnwrapStr<'a> for Option<T> {
-    fn unwrap_as_str(&'a self) -> &'a str {
-        match &self {
-            Some(s) => s.to_str(),
-            None => "",
-        }
-    }
-}
-
-impl<'a, T: ToStr<'a>, E> UnwrapStr<'a> for Result<T, E> {
-    fn unwrap_as_str(&'a self) -> &'a str {
-        match &self {
-            Ok(ok) => ok.to_str(),
-            Err(_) => "",
-        }
-    }
+/// Items used by the code generated by [`render_component!`](crate::render_component), not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::global::{
+        attribute, extra_attributes, flag, listener, merge_classes, yes_no, GlobalAttributes,
+    };
+    pub use crate::MapStr;
 }
 
 // Re-export all elements to avoid having to import all modules.
+pub use crate::accordion::*;
+pub use crate::aria::*;
+pub use crate::autocomplete::*;
+pub use crate::bind::*;
 pub use crate::button::*;
+pub use crate::checkbox::*;
+pub use crate::dropdown::*;
+pub use crate::global::{AutoCapitalize, Dir, EnterKeyHint, GlobalProps, InputMode};
+pub use crate::handle::*;
 pub use crate::header::*;
 pub use crate::init::*;
 pub use crate::input::*;
 pub use crate::list::*;
-pub use dioxus_nui_macros::{include_css, include_css_safe};
+pub use crate::locale::*;
+pub use crate::password::*;
+pub use crate::radio::*;
+pub use crate::roving::*;
+pub use crate::slider::*;
+pub use crate::spin::*;
+pub use crate::switch::*;
+pub use crate::textarea::*;
+pub use dioxus_nui_macros::{include_css, include_css_safe, nui_component, render_component};
 
 /// Re-export of all the elements with the same name as the [`dioxus`](dioxus::prelude::dioxus_elements) ones.
 ///
@@ -200,13 +240,27 @@ pub use dioxus_nui_macros::{include_css, include_css_safe};
 ///
 /// All components unique to dioxus-nui (for example [`List`](crate::List)) will remain with the same name.
 pub mod prelude {
+    pub use crate::accordion::*;
+    pub use crate::autocomplete::*;
     pub use crate::button::Button as button;
+    pub use crate::checkbox::*;
+    pub use crate::dropdown::*;
     pub use crate::header::H1 as h1;
     pub use crate::header::H2 as h2;
     pub use crate::header::H3 as h3;
     pub use crate::header::H4 as h4;
+    pub use crate::handle::*;
     pub use crate::init::*;
+    pub use crate::bind::*;
     pub use crate::input::Input as input;
     pub use crate::list::*;
-    pub use dioxus_nui_macros::{include_css, include_css_safe};
+    pub use crate::locale::*;
+    pub use crate::password::*;
+    pub use crate::radio::*;
+    pub use crate::roving::*;
+    pub use crate::slider::*;
+    pub use crate::spin::*;
+    pub use crate::switch::*;
+    pub use crate::textarea::*;
+    pub use dioxus_nui_macros::{include_css, include_css_safe, nui_component, render_component};
 }
@@ -1,9 +1,12 @@
-use crate::{class, init::CheckIfUninit, Align, UnwrapStr};
-use dioxus::{prelude::*, core::DynamicNode};
-use dioxus_nui_macros::render_component;
-use reusable::reuse;
-
-#[reuse(global_attributes, global_events)]
+use crate::{
+    class::{self, Classes},
+    use_roving_focus, use_roving_item, Align, Orientation, RovingItem,
+};
+use crate::diagnostics::{misuse, use_first_render};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component]
 #[derive(Props)]
 pub struct ListProps<'a> {
     children: Element<'a>,
@@ -14,34 +17,44 @@ pub struct ListProps<'a> {
 /// To work properly, use the [`ListItem`](crate::ListItem) component.
 ///
 /// If another element is used, it will be wrapped in a `ListItem`.
+///
+/// Has the `list` role unless another one is specified.
+///
+/// Its actionable items (the ones with `onclick`) are a single tab stop, moving between them with the Up and Down keys.
 pub fn List<'a>(cx: Scope<'a, ListProps<'a>>) -> Element {
-    let children = cx.props.children.clone();
-    println!("{children:#?}");
-
+    use_roving_focus(cx, Orientation::Vertical);
+    cx.use_hook(|| cx.provide_context(ListContext));
 
     render_component! {
         div {
             $CLASS: "{class::list}",
+            role: cx.props.role.unwrap_or("list"),
             $GLOBALS,
             $CHILDREN
         }
     }
 }
 
-#[reuse(global_attributes, global_events)]
+/// Provided by `List` to its items.
+#[derive(Clone, Copy)]
+struct ListContext;
+
+#[nui_component]
 #[derive(Props)]
 pub struct ListItemProps<'a> {
     /// Title of the list item.
     title: Option<&'a str>,
 
     /// Class of the title, useful if you want to change its style.
-    title_class: Option<&'a str>,
+    #[props(into)]
+    title_class: Option<Classes<'a>>,
 
     /// Subtitle of the list item.
     subtitle: Option<&'a str>,
 
     /// Class of the subtitle, useful if you want to change its style.
-    subtitle_class: Option<&'a str>,
+    #[props(into)]
+    subtitle_class: Option<Classes<'a>>,
 
     /// Alignment of the Title and Subtitle attributes.
     ///
@@ -70,7 +83,19 @@ pub struct ListItemProps<'a> {
 /// Use only nested in a `List` element.
 /// 
 /// Any `children` of the component will be positioned as a `suffix` element.
+///
+/// Has the `listitem` role unless another one is specified.
+///
+/// If it has `onclick`, it can be focused and moved to with the arrow keys from the other items of the `List`.
 pub fn ListItem<'a>(cx: Scope<'a, ListItemProps<'a>>) -> Element {
+    // Only the actionable items are part of the group, the others don't need its listeners
+    let actionable = cx.props.onclick.is_some();
+    let roving = use_roving_item(cx, actionable).filter(|_| actionable);
+
+    if use_first_render(cx) && cx.consume_context::<ListContext>().is_none() {
+        misuse!("ListItem", "used outside of a `List`, it won't be styled as part of a list");
+    }
+
     let ListItemProps {
         title,
         title_class,
@@ -83,14 +108,16 @@ pub fn ListItem<'a>(cx: Scope<'a, ListItemProps<'a>>) -> Element {
     } = cx.props;
 
     let title = title.map(|t| {
+        let class = Classes::from(class::list_item_title).merge(title_class.as_ref());
         rsx! {
-            p { class: "{class::list_item_title} {title_class.unwrap_as_str()}", t }
+            p { class: "{class}", t }
         }
     });
 
     let subtitle = subtitle.map(|t| {
+        let class = Classes::from(class::list_item_subtitle).merge(subtitle_class.as_ref());
         rsx! {
-            p { class: "{class::list_item_subtitle} {subtitle_class.unwrap_as_str()}", t }
+            p { class: "{class}", t }
         }
     });
 
@@ -109,7 +136,12 @@ pub fn ListItem<'a>(cx: Scope<'a, ListItemProps<'a>>) -> Element {
     render_component! {
         div {
             $CLASS: "{class::list_item}",
-            $GLOBALS,
+            role: cx.props.role.unwrap_or("listitem"),
+            tabindex: cx.props.tabindex.or(roving.and_then(RovingItem::tabindex)).map(i64::from),
+            "data-nui-prevent-keys": roving.and_then(RovingItem::prevent_keys),
+            $GLOBALS: roving
+                .map(|item| item.listeners(cx, &cx.props.onkeydown, &cx.props.onfocus, &cx.props.onmounted))
+                .unwrap_or_default(),
             prefix,
             div { class: "{align}",
                 title,
@@ -0,0 +1,369 @@
+//! Localization of the strings and formats produced by NUI components.
+//!
+//! [`InitNui`](crate::InitNui) provides a [`Localization`], made of a [`Locale`] (how numbers and dates are written)
+//! and a catalog of [`Messages`] (the strings shown by components, like "Search…" or "No results").
+//!
+//! # Example
+//! ```
+//! struct Spanish;
+//!
+//! impl Messages for Spanish {
+//!     fn search(&self) -> &str {
+//!         "Buscar…"
+//!     }
+//!     // Every other message keeps its English default
+//! }
+//!
+//! render! {
+//!     InitNui { locale: Locale::ES, messages: Rc::new(Spanish) }
+//! }
+//! ```
+
+use dioxus::prelude::ScopeState;
+use std::rc::Rc;
+
+/// How numbers and dates are written.
+///
+/// `Locale::EN_US` by default.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Locale {
+    /// [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag, like `"en-US"`.
+    pub tag: &'static str,
+    /// Separator between the integer and the fractional part of a number.
+    pub decimal_separator: char,
+    /// Separator between groups of thousands, `None` to not group them.
+    pub group_separator: Option<char>,
+    /// Order of the day, month and year in a date.
+    pub date_order: DateOrder,
+    /// Separator between the day, month and year in a date.
+    pub date_separator: char,
+}
+
+impl Locale {
+    pub const EN_US: Locale = Locale {
+        tag: "en-US",
+        decimal_separator: '.',
+        group_separator: Some(','),
+        date_order: DateOrder::Mdy,
+        date_separator: '/',
+    };
+    pub const EN_GB: Locale = Locale {
+        tag: "en-GB",
+        date_order: DateOrder::Dmy,
+        ..Locale::EN_US
+    };
+    pub const DE: Locale = Locale {
+        tag: "de",
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        date_order: DateOrder::Dmy,
+        date_separator: '.',
+    };
+    pub const ES: Locale = Locale {
+        tag: "es",
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        date_order: DateOrder::Dmy,
+        date_separator: '/',
+    };
+    pub const FR: Locale = Locale {
+        tag: "fr",
+        decimal_separator: ',',
+        group_separator: Some('\u{202F}'),
+        date_order: DateOrder::Dmy,
+        date_separator: '/',
+    };
+    pub const JA: Locale = Locale {
+        tag: "ja",
+        decimal_separator: '.',
+        group_separator: Some(','),
+        date_order: DateOrder::Ymd,
+        date_separator: '/',
+    };
+    /// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) dates and numbers without grouping.
+    pub const ISO: Locale = Locale {
+        tag: "und",
+        decimal_separator: '.',
+        group_separator: None,
+        date_order: DateOrder::Ymd,
+        date_separator: '-',
+    };
+
+    /// Returns the predefined locale matching `tag` (like `"de-AT"` or `"en_GB.UTF-8"`), if any.
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let tag = tag.split('.').next()?.replace('_', "-").to_lowercase();
+        let language = tag.split('-').next()?;
+
+        match (language, tag.as_str()) {
+            (_, "en-gb") => Some(Locale::EN_GB),
+            ("en", _) => Some(Locale::EN_US),
+            ("de", _) => Some(Locale::DE),
+            ("es", _) => Some(Locale::ES),
+            ("fr", _) => Some(Locale::FR),
+            ("ja", _) => Some(Locale::JA),
+            _ => None,
+        }
+    }
+
+    /// Formats `value` with `digits` fractional digits.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(Locale::DE.format_number(1234.5, 2), "1.234,50");
+    /// ```
+    pub fn format_number(&self, value: f64, digits: usize) -> String {
+        let formatted = format!("{:.*}", digits, value.abs());
+        let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+        let mut out = String::with_capacity(formatted.len() + integer.len() / 3 + 1);
+        // "-0.00" is written as "0.00"
+        if value.is_sign_negative() && formatted.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+            out.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            if let Some(separator) = self.group_separator {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    out.push(separator);
+                }
+            }
+            out.push(digit);
+        }
+        if !fraction.is_empty() {
+            out.push(self.decimal_separator);
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// Parses a number written by the user, `None` if it's not valid.
+    ///
+    /// The integer part can be grouped by thousands with the group separator, or with spaces if it's a space.
+    /// `.` is accepted as decimal separator too, unless it's the group separator:
+    /// `"2.5"` is `None` with `Locale::DE`, where `.` only groups thousands.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(Locale::DE.parse_number("1.234,5"), Some(1234.5));
+    /// assert_eq!(Locale::FR.parse_number("1 234.5"), Some(1234.5));
+    /// ```
+    pub fn parse_number(&self, value: &str) -> Option<f64> {
+        let value = value.trim();
+        let (integer, fraction) = match value.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None if self.group_separator != Some('.') => match value.split_once('.') {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (value, None),
+            },
+            None => (value, None),
+        };
+
+        let is_group_separator = |c: char| match self.group_separator {
+            Some(separator) if separator.is_whitespace() => c.is_whitespace(),
+            separator => Some(c) == separator,
+        };
+        let mut groups = integer.split(is_group_separator);
+        let mut number = groups.next().unwrap_or_default().to_string();
+        let leading = number.trim_start_matches(|c| c == '-' || c == '+').len();
+        for group in groups {
+            if !(1..=3).contains(&leading) || group.len() != 3 {
+                return None;
+            }
+            number.push_str(group);
+        }
+        if let Some(fraction) = fraction {
+            number.push('.');
+            number.push_str(fraction);
+        }
+
+        number.parse().ok()
+    }
+
+    /// Formats a date, `month` and `day` start at 1.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(Locale::EN_US.format_date(2023, 7, 4), "07/04/2023");
+    /// ```
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        let s = self.date_separator;
+        match self.date_order {
+            DateOrder::Dmy => format!("{day:02}{s}{month:02}{s}{year}"),
+            DateOrder::Mdy => format!("{month:02}{s}{day:02}{s}{year}"),
+            DateOrder::Ymd => format!("{year}{s}{month:02}{s}{day:02}"),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN_US
+    }
+}
+
+/// Order of the day, month and year in a date.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DateOrder {
+    /// Day, month, year.
+    Dmy,
+    /// Month, day, year.
+    Mdy,
+    /// Year, month, day.
+    Ymd,
+}
+
+/// Catalog of the strings shown by NUI components.
+///
+/// Every message has an English default, so only the ones that are translated need to be implemented.
+pub trait Messages {
+    /// Placeholder of search fields.
+    fn search(&self) -> &str {
+        "Search…"
+    }
+    /// Placeholder of fields where an option has to be selected.
+    fn select(&self) -> &str {
+        "Select…"
+    }
+    /// Shown when a search or suggestion list is empty.
+    fn no_results(&self) -> &str {
+        "No results"
+    }
+    /// Label of the button that increases a value.
+    fn increase(&self) -> &str {
+        "Increase"
+    }
+    /// Label of the button that decreases a value.
+    fn decrease(&self) -> &str {
+        "Decrease"
+    }
+    /// Label of the button that reveals a password.
+    fn show_password(&self) -> &str {
+        "Show password"
+    }
+    /// Warning shown while typing a password with Caps Lock on.
+    fn caps_lock_on(&self) -> &str {
+        "Caps Lock is on"
+    }
+    /// Character counter of text fields, `max` is the maximum length if there's one.
+    fn characters(&self, count: usize, max: Option<usize>) -> String {
+        match max {
+            Some(max) => format!("{count}/{max}"),
+            None if count == 1 => "1 character".to_string(),
+            None => format!("{count} characters"),
+        }
+    }
+    /// Name of the `month`, from 1 (January) to 12 (December), empty for other values.
+    fn month(&self, month: u32) -> &str {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        month
+            .checked_sub(1)
+            .and_then(|month| MONTHS.get(month as usize))
+            .copied()
+            .unwrap_or_default()
+    }
+    /// Short name of the `weekday`, from 0 (Monday) to 6 (Sunday).
+    fn weekday(&self, weekday: u32) -> &str {
+        const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        WEEKDAYS[weekday as usize % 7]
+    }
+    /// Units of file sizes, from bytes to terabytes.
+    fn file_size_units(&self) -> [&str; 5] {
+        ["B", "kB", "MB", "GB", "TB"]
+    }
+}
+
+/// The English messages, used by default.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl Messages for English {}
+
+/// Locale and messages used by NUI components, provided by [`InitNui`](crate::InitNui).
+///
+/// Get it with [`use_localization`].
+#[derive(Clone)]
+pub struct Localization {
+    pub locale: Locale,
+    pub messages: Rc<dyn Messages>,
+}
+
+impl Localization {
+    /// Formats `value` with `digits` fractional digits, see [`Locale::format_number`].
+    pub fn format_number(&self, value: f64, digits: usize) -> String {
+        self.locale.format_number(value, digits)
+    }
+
+    /// Formats a date, see [`Locale::format_date`].
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        self.locale.format_date(year, month, day)
+    }
+
+    /// Formats a size in bytes with the largest fitting unit (powers of 1000).
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(localization.format_file_size(1_500_000), "1.5 MB");
+    /// ```
+    pub fn format_file_size(&self, bytes: u64) -> String {
+        let units = self.messages.file_size_units();
+        let mut size = bytes as f64;
+        let mut unit = 0;
+        // Compared once rounded, so 999 999 bytes are "1 MB" and not "1,000 kB"
+        while size.round() >= 1000.0 && unit < units.len() - 1 {
+            size /= 1000.0;
+            unit += 1;
+        }
+
+        let digits = match unit {
+            0 => 0,
+            _ if size < 10.0 => 1,
+            _ => 0,
+        };
+        let number = self.format_number(size, digits);
+        // "1.0 kB" is written as "1 kB"
+        let zero = format!("{}0", self.locale.decimal_separator);
+        let number = number.strip_suffix(&zero).unwrap_or(&number);
+
+        format!("{number} {}", units[unit])
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Localization {
+            locale: Locale::default(),
+            messages: Rc::new(English),
+        }
+    }
+}
+
+impl PartialEq for Localization {
+    fn eq(&self, other: &Self) -> bool {
+        self.locale == other.locale && Rc::ptr_eq(&self.messages, &other.messages)
+    }
+}
+
+impl std::fmt::Debug for Localization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Localization")
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the [`Localization`] provided by [`InitNui`](crate::InitNui), or the English one if there's none.
+pub fn use_localization(cx: &ScopeState) -> Localization {
+    cx.consume_context::<Localization>().unwrap_or_default()
+}
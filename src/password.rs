@@ -0,0 +1,277 @@
+use crate::{
+    class,
+    icons::{Eye, EyeOff},
+    use_localization, InputType, ToStr,
+};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct PasswordEntryProps<'a> {
+    /// Password, the typed one is kept if not specified.
+    value: Option<&'a str>,
+    name: Option<&'a str>,
+    label: Option<&'a str>,
+
+    /// Called with the password every time it changes.
+    on_change: Option<EventHandler<'a, String>>,
+
+    /// Rates the password to show a strength meter, like [`PasswordStrength::estimate`].
+    ///
+    /// No meter is shown if not specified.
+    strength: Option<&'a dyn Fn(&str) -> PasswordStrength>,
+
+    /// How the browser fills the password, like `"one-time-code"`.
+    ///
+    /// `"new-password"` by default if `strength` is set (to suggest a generated one), `"current-password"` if not.
+    autocomplete: Option<&'a str>,
+}
+
+/// Password field with a button to show it and a warning when Caps Lock is on,
+/// like `AdwPasswordEntryRow`.
+///
+/// Caps Lock is read from the modifiers of the key events in the field.
+/// Renderers that don't report it fall back to the letters typed, so the warning appears after the first one.
+///
+/// # Example
+/// ```
+/// render! {
+///     PasswordEntry {
+///         label: "New password",
+///         strength: &PasswordStrength::estimate,
+///         on_change: move |password| println!("{password}")
+///     }
+/// }
+/// ```
+pub fn PasswordEntry<'a>(cx: Scope<'a, PasswordEntryProps<'a>>) -> Element<'a> {
+    let PasswordEntryProps {
+        value,
+        name,
+        label,
+        on_change,
+        strength,
+        autocomplete,
+        oninput,
+        onkeydown,
+        onkeyup,
+        onblur,
+        ..
+    } = cx.props;
+
+    let typed = use_state(cx, || value.unwrap_or_default().to_string());
+    let visible = use_state(cx, || false);
+    let caps_lock = use_state(cx, || false);
+    let password = value.unwrap_or(typed.as_str());
+
+    let autocomplete = autocomplete.unwrap_or(match strength {
+        Some(_) => "new-password",
+        None => "current-password",
+    });
+
+    let messages = use_localization(cx).messages;
+    // The label stays the same, `aria-pressed` tells whether the password is shown
+    let toggle = messages.show_password();
+    let caps_lock_on = caps_lock.get().then(|| messages.caps_lock_on());
+    let strength = strength
+        .filter(|_| !password.is_empty())
+        .map(|strength| strength(password));
+
+    render_component! {
+        label {
+            class: "{class::label}",
+            *label,
+            div {
+                $CLASS: "{class::password}",
+                input {
+                    class: InputType::Password.to_style(),
+                    r#type: if *visible.get() { "text" } else { "password" },
+                    name: *name,
+                    value: password,
+                    autocomplete: autocomplete,
+                    oninput: move |e: Event<FormData>| {
+                        typed.set(e.value.clone());
+                        if let Some(on_change) = on_change {
+                            on_change.call(e.value.clone());
+                        }
+                        if let Some(oninput) = oninput {
+                            oninput.call(e);
+                        }
+                    },
+                    onkeydown: move |e: KeyboardEvent| {
+                        if let Some(on) = caps_lock_state(&e.key(), e.modifiers()) {
+                            caps_lock.set(on);
+                        }
+                        if let Some(onkeydown) = onkeydown {
+                            onkeydown.call(e);
+                        }
+                    },
+                    // Releasing Caps Lock reports its new state on some platforms
+                    onkeyup: move |e: KeyboardEvent| {
+                        if let Some(on) = caps_lock_state(&e.key(), e.modifiers()) {
+                            caps_lock.set(on);
+                        }
+                        if let Some(onkeyup) = onkeyup {
+                            onkeyup.call(e);
+                        }
+                    },
+                    onblur: move |e: FocusEvent| {
+                        caps_lock.set(false);
+                        if let Some(onblur) = onblur {
+                            onblur.call(e);
+                        }
+                    },
+                    $GLOBALS,
+                }
+                button {
+                    class: "{class::password_toggle}",
+                    r#type: "button",
+                    "aria-label": "{toggle}",
+                    "aria-pressed": visible.get().to_str(),
+                    onclick: move |_| visible.set(!visible.get()),
+                    if *visible.get() {
+                        rsx! { EyeOff {} }
+                    } else {
+                        rsx! { Eye {} }
+                    }
+                }
+            }
+        }
+        caps_lock_on.map(|caps_lock_on| rsx! {
+            span { class: "{class::password_caps_lock}", role: "status", "{caps_lock_on}" }
+        })
+        strength.map(|strength| rsx! {
+            meter {
+                class: "{class::password_strength}",
+                "data-strength": strength.to_str(),
+                min: 0,
+                max: 4,
+                low: 2,
+                high: 3,
+                optimum: 4,
+                value: strength.score() as i64,
+            }
+        })
+    }
+}
+
+/// Returns the state of Caps Lock when `key` is pressed or released with `modifiers`, `None` if it can't be known.
+///
+/// Without `Modifiers::CAPS_LOCK`, letters typed without Shift are uppercase with Caps Lock on, and lowercase with Shift.
+fn caps_lock_state(key: &Key, modifiers: Modifiers) -> Option<bool> {
+    if modifiers.contains(Modifiers::CAPS_LOCK) {
+        return Some(true);
+    }
+    let shift = modifiers.contains(Modifiers::SHIFT);
+    match key {
+        Key::CapsLock => Some(false),
+        Key::Character(c) => {
+            let mut chars = c.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_uppercase() => Some(!shift),
+                (Some(c), None) if c.is_lowercase() => Some(shift),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Strength of a password, shown by the meter of a [`PasswordEntry`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Rough estimate based on the length and the kinds of characters used.
+    ///
+    /// Doesn't check for common passwords or patterns, use a dedicated crate for that.
+    pub fn estimate(password: &str) -> PasswordStrength {
+        let length = password.chars().count();
+        let kinds = [
+            password.chars().any(char::is_lowercase),
+            password.chars().any(char::is_uppercase),
+            password.chars().any(char::is_numeric),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ];
+        let variety = kinds.iter().filter(|&&used| used).count();
+
+        let points = [length >= 8, length >= 12, length >= 16, variety >= 3]
+            .iter()
+            .filter(|&&point| point)
+            .count();
+        match points {
+            0 | 1 => PasswordStrength::Weak,
+            2 => PasswordStrength::Fair,
+            3 => PasswordStrength::Good,
+            _ => PasswordStrength::Strong,
+        }
+    }
+
+    /// Value of the meter, from 1 (`Weak`) to 4 (`Strong`).
+    pub fn score(&self) -> u8 {
+        *self as u8 + 1
+    }
+}
+
+impl ToStr<'static> for PasswordStrength {
+    fn to_str(&self) -> &'static str {
+        match self {
+            PasswordStrength::Weak => "weak",
+            PasswordStrength::Fair => "fair",
+            PasswordStrength::Good => "good",
+            PasswordStrength::Strong => "strong",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::caps_lock_state;
+    use dioxus::prelude::{Key, Modifiers};
+
+    fn letter(c: &str) -> Key {
+        Key::Character(c.to_string())
+    }
+
+    #[test]
+    fn reported_caps_lock() {
+        assert_eq!(
+            caps_lock_state(&Key::CapsLock, Modifiers::CAPS_LOCK),
+            Some(true)
+        );
+        assert_eq!(
+            caps_lock_state(&Key::CapsLock, Modifiers::empty()),
+            Some(false)
+        );
+        assert_eq!(
+            caps_lock_state(&letter("a"), Modifiers::CAPS_LOCK | Modifiers::SHIFT),
+            Some(true)
+        );
+        assert_eq!(
+            caps_lock_state(&Key::Enter, Modifiers::CAPS_LOCK),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn guessed_caps_lock() {
+        assert_eq!(
+            caps_lock_state(&letter("A"), Modifiers::empty()),
+            Some(true)
+        );
+        assert_eq!(
+            caps_lock_state(&letter("a"), Modifiers::empty()),
+            Some(false)
+        );
+        assert_eq!(caps_lock_state(&letter("a"), Modifiers::SHIFT), Some(true));
+        assert_eq!(caps_lock_state(&letter("A"), Modifiers::SHIFT), Some(false));
+        // Not letters, the state is kept
+        assert_eq!(caps_lock_state(&letter("1"), Modifiers::empty()), None);
+        assert_eq!(caps_lock_state(&Key::Enter, Modifiers::empty()), None);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{class, MapStr, Orientation, ToStr};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component]
+#[derive(Props)]
+pub struct RadioGroupProps<'a, T: PartialEq + Clone + 'a> {
+    /// Values of the options and their labels.
+    options: &'a [(T, &'a str)],
+
+    /// Value of the selected option, none if `None` or if it's not in `options`.
+    ///
+    /// Passed as a plain `T` (`selected: value`), it's only optional so a group can start with nothing selected,
+    /// like a question of a form that isn't answered yet.
+    selected: Option<T>,
+
+    /// Called with the value of the option when it's selected.
+    on_select: Option<EventHandler<'a, T>>,
+
+    /// Layout of the options.
+    ///
+    /// `Orientation::Vertical` by default.
+    #[props(default = Orientation::Vertical)]
+    orientation: Orientation,
+
+    /// Name of the radios in a form, a unique one is generated if not specified.
+    name: Option<&'a str>,
+    disabled: Option<bool>,
+}
+
+/// Group of radio buttons where only one option can be selected.
+///
+/// Tab focuses the selected option, and the arrow keys select the previous or next one.
+///
+/// # Example
+/// ```
+/// #[derive(PartialEq, Clone, Copy)]
+/// enum Size { Small, Medium, Large }
+///
+/// let size = use_state(cx, || Size::Medium);
+///
+/// render! {
+///     RadioGroup {
+///         aria_label: "Size",
+///         options: &[(Size::Small, "Small"), (Size::Medium, "Medium"), (Size::Large, "Large")],
+///         selected: *size.get(),
+///         on_select: move |s| size.set(s)
+///     }
+/// }
+/// ```
+pub fn RadioGroup<'a, T: PartialEq + Clone + 'a>(
+    cx: Scope<'a, RadioGroupProps<'a, T>>,
+) -> Element<'a> {
+    let RadioGroupProps {
+        options,
+        selected,
+        on_select,
+        orientation,
+        name,
+        disabled,
+        ..
+    } = cx.props;
+
+    // Radios with the same name are a single tab stop, moved between with the arrow keys by the browser
+    let generated = cx.use_hook(|| format!("nui-radio-group-{}", cx.scope_id().0));
+    let name = name.unwrap_or(generated.as_str());
+    let layout = match orientation {
+        Orientation::Horizontal => class::radio_group_horizontal,
+        Orientation::Vertical => class::radio_group_vertical,
+    };
+
+    render_component! {
+        div {
+            $CLASS: "{class::radio_group} {layout}",
+            role: "radiogroup",
+            "aria-disabled": disabled.map_str(),
+            $GLOBALS,
+            for (value, label) in options.iter() {
+                label { class: "{class::radio}",
+                    input {
+                        class: "{class::radio_input}",
+                        r#type: "radio",
+                        name: name,
+                        disabled: disabled.map_str(),
+                        checked: (selected.as_ref() == Some(value)).to_str(),
+                        onchange: move |_| {
+                            if let Some(on_select) = on_select {
+                                on_select.call(value.clone());
+                            }
+                        }
+                    }
+                    span { class: "{class::radio_mark}", "aria-hidden": "true" }
+                    *label
+                }
+            }
+        }
+    }
+}
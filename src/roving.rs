@@ -0,0 +1,439 @@
+//! Roving tabindex: only one item of a group is a tab stop, and the arrow keys move the focus between them.
+//!
+//! Tab moves between widgets while the arrow keys move within them, as in native toolkits.
+//!
+//! [`List`](crate::List) is a vertical group of its actionable [`ListItem`](crate::ListItem)s (the ones with `onclick`),
+//! and [`RovingGroup`] makes a group of any [`Button`](crate::Button)s inside it.
+//!
+//! # Example
+//! ```
+//! // Toolbar, Tab focuses "Bold" (or the last one focused) and the arrow keys move to "Italic" and "Underline"
+//! RovingGroup {
+//!     orientation: Orientation::Horizontal,
+//!     Button { "Bold" }
+//!     Button { "Italic" }
+//!     Button { "Underline" }
+//! }
+//! ```
+//!
+//! Custom components join the nearest group with [`use_roving_item`].
+
+use crate::{accordion::Collapsed, ElementHandle, Orientation};
+use dioxus::{core::Attribute, prelude::*};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+/// Group of items where only one is a tab stop, created with [`use_roving_focus`].
+///
+/// Provided as a context to the children of the component that creates it.
+#[derive(Clone)]
+pub struct RovingFocus(Option<Rc<RefCell<Group>>>);
+
+struct Group {
+    orientation: Orientation,
+    /// Enabled items, in the order they were rendered
+    items: Vec<Item>,
+    /// Item that is the tab stop, the first one if `None`
+    active: Option<usize>,
+    next_id: usize,
+    /// Incremented on every render of the group, items are placed again on their first render after it
+    render: usize,
+    /// Number of items placed since the group was rendered
+    placed: usize,
+}
+
+struct Item {
+    id: usize,
+    /// Render of the group the item was last placed in
+    render: usize,
+    handle: ElementHandle,
+    update: Arc<dyn Fn() + Send + Sync>,
+    collapsed: Option<Collapsed>,
+}
+
+impl Item {
+    /// Returns `false` if the item is in a collapsed `ExpanderRow`, which hides it.
+    fn is_visible(&self) -> bool {
+        !self.collapsed.as_ref().map_or(false, Collapsed::get)
+    }
+}
+
+impl Group {
+    fn active(&self) -> Option<usize> {
+        let mut visible = self.items.iter().filter(|item| item.is_visible());
+        match self.active {
+            Some(active) if visible.clone().any(|item| item.id == active) => Some(active),
+            _ => visible.next().map(|item| item.id),
+        }
+    }
+
+    /// Moves the item after the ones already rendered, so the order follows the one of the elements
+    /// when items are inserted or moved.
+    fn place(&mut self, item: &RovingItem) {
+        let position = self.items.iter().position(|i| i.id == item.id);
+        if let Some(position) = position {
+            if self.items[position].render == self.render {
+                return;
+            }
+            self.items.remove(position);
+        }
+
+        let index = self.placed.min(self.items.len());
+        self.items.insert(
+            index,
+            Item {
+                id: item.id,
+                render: self.render,
+                handle: item.handle.clone(),
+                update: item.update.clone(),
+                collapsed: item.collapsed.clone(),
+            },
+        );
+        self.placed = index + 1;
+    }
+}
+
+/// Creates a group of items where only one is a tab stop, and provides it to the children.
+///
+/// The focus is moved with the arrow keys of the `orientation` (Up/Down or Left/Right), and with Home/End.
+pub fn use_roving_focus(cx: &ScopeState, orientation: Orientation) -> &RovingFocus {
+    let focus = cx.use_hook(|| {
+        let focus = RovingFocus(Some(Rc::new(RefCell::new(Group {
+            orientation,
+            items: Vec::new(),
+            active: None,
+            next_id: 0,
+            render: 0,
+            placed: 0,
+        }))));
+        cx.provide_context(focus.clone());
+        focus
+    });
+
+    if let Some(group) = &focus.0 {
+        let mut group = group.borrow_mut();
+        group.orientation = orientation;
+        // The items are rendered after the group, in the order of their elements
+        group.render += 1;
+        group.placed = 0;
+    }
+    focus
+}
+
+/// Adds the component to the nearest [`RovingFocus`] group, `None` if it's not inside one.
+///
+/// Only `enabled` items are part of the group: they can be focused with the arrow keys, in the order they're rendered.
+/// Items in a collapsed [`ExpanderRow`](crate::ExpanderRow) are skipped.
+///
+/// Components inside the item are not part of the group.
+///
+/// The item must set its `tabindex` to [`RovingItem::tabindex`], `data-nui-prevent-keys` to [`RovingItem::prevent_keys`]
+/// and add the [`RovingItem::listeners`] to its element,
+/// or call [`RovingItem::keydown`], [`RovingItem::activate`] and [`RovingItem::mounted`] from its own listeners.
+pub fn use_roving_item(cx: &ScopeState, enabled: bool) -> Option<&RovingItem> {
+    let item = cx.use_hook(|| {
+        let group = cx.consume_context::<RovingFocus>()?.0?;
+        let id = {
+            let mut group = group.borrow_mut();
+            group.next_id += 1;
+            group.next_id
+        };
+        // Taken before an `ExpanderRow` provides its own
+        let collapsed = cx.consume_context::<Collapsed>();
+        // Nested items (like a `Button` in a `ListItem`) belong to their own group
+        cx.provide_context(RovingFocus(None));
+
+        Some(RovingItem {
+            id,
+            group,
+            handle: ElementHandle::default(),
+            update: cx.schedule_update(),
+            collapsed,
+        })
+    });
+
+    let item = item.as_ref()?;
+    item.set_enabled(enabled);
+    Some(item)
+}
+
+/// Item of a [`RovingFocus`] group, created with [`use_roving_item`].
+///
+/// Removed from the group when the component is dropped.
+pub struct RovingItem {
+    id: usize,
+    group: Rc<RefCell<Group>>,
+    handle: ElementHandle,
+    update: Arc<dyn Fn() + Send + Sync>,
+    collapsed: Option<Collapsed>,
+}
+
+impl RovingItem {
+    /// Returns the `tabindex` of the item, `0` if it's the tab stop of the group and `-1` if not.
+    ///
+    /// `None` if the item is disabled.
+    pub fn tabindex(&self) -> Option<i32> {
+        if !self.is_enabled() {
+            return None;
+        }
+        match self.group.borrow().active() == Some(self.id) {
+            true => Some(0),
+            false => Some(-1),
+        }
+    }
+
+    /// Returns `true` if the item can be focused with the arrow keys.
+    pub fn is_enabled(&self) -> bool {
+        self.group
+            .borrow()
+            .items
+            .iter()
+            .any(|item| item.id == self.id && item.is_visible())
+    }
+
+    /// Returns the keys that move the focus, for the `data-nui-prevent-keys` attribute of the item.
+    ///
+    /// They would otherwise scroll the page. `None` if the item is disabled.
+    pub fn prevent_keys(&self) -> Option<&'static str> {
+        if !self.is_enabled() {
+            return None;
+        }
+        match self.group.borrow().orientation {
+            Orientation::Vertical => Some("ArrowUp ArrowDown Home End"),
+            Orientation::Horizontal => Some("ArrowLeft ArrowRight Home End"),
+        }
+    }
+
+    /// Makes this item the tab stop of the group, called when it receives focus.
+    pub fn activate(&self) {
+        activate(&self.group, self.id);
+    }
+
+    /// Keeps the element of the item to focus it with the arrow keys, called when it's mounted.
+    ///
+    /// Also needed while the item is disabled, as it may be enabled later.
+    pub fn mounted(&self, event: MountedEvent) {
+        self.handle.set(event);
+    }
+
+    /// Moves the focus if `event` is one of the navigation keys of the group.
+    ///
+    /// Returns `true` if the focus was moved.
+    pub fn keydown(&self, cx: &ScopeState, event: &KeyboardEvent) -> bool {
+        let target = {
+            let group = self.group.borrow();
+            let enabled: Vec<&Item> = group.items.iter().filter(|item| item.is_visible()).collect();
+            let Some(position) = enabled.iter().position(|item| item.id == self.id) else {
+                return false;
+            };
+
+            let index = match (group.orientation, event.key()) {
+                (Orientation::Vertical, Key::ArrowUp)
+                | (Orientation::Horizontal, Key::ArrowLeft) => position.checked_sub(1),
+                (Orientation::Vertical, Key::ArrowDown)
+                | (Orientation::Horizontal, Key::ArrowRight) => {
+                    Some(position + 1).filter(|&i| i < enabled.len())
+                }
+                (_, Key::Home) => Some(0),
+                (_, Key::End) => Some(enabled.len() - 1),
+                _ => None,
+            };
+
+            match index.map(|i| enabled[i]) {
+                Some(target) if target.id != self.id => (target.id, target.handle.clone()),
+                _ => return false,
+            }
+        };
+
+        let (id, handle) = target;
+        activate(&self.group, id);
+        cx.spawn(async move {
+            let _ = handle.focus().await;
+        });
+        true
+    }
+
+    /// Listeners of the item, which call the user's handlers after moving the focus.
+    ///
+    /// Meant to be added with `$GLOBALS: listeners` in [`render_component!`](crate::render_component).
+    pub fn listeners<'a>(
+        &'a self,
+        cx: &'a ScopeState,
+        onkeydown: &'a Option<EventHandler<'a, KeyboardEvent>>,
+        onfocus: &'a Option<EventHandler<'a, FocusEvent>>,
+        onmounted: &'a Option<EventHandler<'a, MountedEvent>>,
+    ) -> Vec<Attribute<'a>> {
+        let keydown = cx.listener(move |e: KeyboardEvent| {
+            self.keydown(cx, &e);
+            if let Some(onkeydown) = onkeydown {
+                onkeydown.call(e);
+            }
+        });
+        let focus = cx.listener(move |e: FocusEvent| {
+            self.activate();
+            if let Some(onfocus) = onfocus {
+                onfocus.call(e);
+            }
+        });
+        let mounted = cx.listener(move |e: MountedEvent| {
+            self.mounted(e.clone());
+            if let Some(onmounted) = onmounted {
+                onmounted.call(e);
+            }
+        });
+
+        vec![
+            Attribute::new("onkeydown", keydown, None, false),
+            Attribute::new("onfocus", focus, None, false),
+            Attribute::new("onmounted", mounted, None, false),
+        ]
+    }
+
+    fn set_enabled(&self, enabled: bool) {
+        let mut group = self.group.borrow_mut();
+        if enabled {
+            group.place(self);
+        } else {
+            group.items.retain(|item| item.id != self.id);
+        }
+    }
+}
+
+/// Makes the item `id` the tab stop of `group`.
+fn activate(group: &RefCell<Group>, id: usize) {
+    let updates: Vec<_> = {
+        let mut group = group.borrow_mut();
+        if group.active() == Some(id) {
+            return;
+        }
+        group.active = Some(id);
+        group.items.iter().map(|item| item.update.clone()).collect()
+    };
+    // Every item re-renders with its new `tabindex`
+    updates.iter().for_each(|update| update());
+}
+
+impl Drop for RovingItem {
+    fn drop(&mut self) {
+        self.group
+            .borrow_mut()
+            .items
+            .retain(|item| item.id != self.id);
+    }
+}
+
+#[derive(Props)]
+pub struct RovingGroupProps<'a> {
+    /// Direction of the arrow keys that move the focus.
+    #[props(default)]
+    orientation: Orientation,
+    children: Element<'a>,
+}
+
+/// Makes the [`Button`](crate::Button)s inside it a single tab stop, moving between them with the arrow keys.
+///
+/// Useful for toolbars, button groups and menus. Doesn't render any element.
+pub fn RovingGroup<'a>(cx: Scope<'a, RovingGroupProps<'a>>) -> Element<'a> {
+    use_roving_focus(cx, cx.props.orientation);
+
+    render! { &cx.props.children }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> Rc<RefCell<Group>> {
+        Rc::new(RefCell::new(Group {
+            orientation: Orientation::Vertical,
+            items: Vec::new(),
+            active: None,
+            next_id: 0,
+            render: 0,
+            placed: 0,
+        }))
+    }
+
+    fn item(group: &Rc<RefCell<Group>>, id: usize) -> RovingItem {
+        RovingItem {
+            id,
+            group: group.clone(),
+            handle: ElementHandle::default(),
+            update: Arc::new(|| {}),
+            collapsed: None,
+        }
+    }
+
+    fn render(group: &Rc<RefCell<Group>>, items: &[&RovingItem]) {
+        {
+            let mut group = group.borrow_mut();
+            group.render += 1;
+            group.placed = 0;
+        }
+        items.iter().for_each(|item| item.set_enabled(true));
+    }
+
+    fn order(group: &Rc<RefCell<Group>>) -> Vec<usize> {
+        group.borrow().items.iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn render_order() {
+        let group = group();
+        let (first, second, third) = (item(&group, 1), item(&group, 2), item(&group, 3));
+
+        render(&group, &[&first, &third]);
+        assert_eq!(order(&group), [1, 3]);
+
+        // Inserted in the middle after the others were created
+        render(&group, &[&first, &second, &third]);
+        assert_eq!(order(&group), [1, 2, 3]);
+
+        // Rendering again without the group doesn't move the item
+        first.set_enabled(true);
+        assert_eq!(order(&group), [1, 2, 3]);
+
+        render(&group, &[&third, &first, &second]);
+        assert_eq!(order(&group), [3, 1, 2]);
+    }
+
+    #[test]
+    fn disabled_items() {
+        let group = group();
+        let (first, second) = (item(&group, 1), item(&group, 2));
+
+        render(&group, &[&first, &second]);
+        first.set_enabled(false);
+        assert_eq!(order(&group), [2]);
+        assert_eq!(first.tabindex(), None);
+        assert_eq!(first.prevent_keys(), None);
+        // The first enabled item is the tab stop
+        assert_eq!(second.tabindex(), Some(0));
+        assert_eq!(second.prevent_keys(), Some("ArrowUp ArrowDown Home End"));
+
+        drop(second);
+        assert!(order(&group).is_empty());
+    }
+
+    #[test]
+    fn collapsed_items() {
+        let group = group();
+        let collapsed = Collapsed::default();
+        let first = item(&group, 1);
+        let hidden = RovingItem {
+            id: 2,
+            group: group.clone(),
+            handle: ElementHandle::default(),
+            update: Arc::new(|| {}),
+            collapsed: Some(collapsed.clone()),
+        };
+
+        render(&group, &[&hidden, &first]);
+        collapsed.set(true);
+        assert_eq!(hidden.tabindex(), None);
+        assert_eq!(first.tabindex(), Some(0));
+
+        collapsed.set(false);
+        assert_eq!(hidden.tabindex(), Some(0));
+        assert_eq!(first.tabindex(), Some(-1));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{class, MapStr, Orientation};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct SliderProps<'a> {
+    /// Current value.
+    value: f64,
+
+    /// `0.0` by default.
+    #[props(default = 0.0)]
+    min: f64,
+
+    /// `100.0` by default.
+    #[props(default = 100.0)]
+    max: f64,
+
+    /// Granularity of the value, `1.0` by default.
+    #[props(default = 1.0)]
+    step: f64,
+
+    /// Tick marks drawn along the track, with an optional label.
+    #[props(default)]
+    marks: &'a [(f64, Option<&'a str>)],
+
+    /// `Orientation::Horizontal` by default, vertical sliders have the minimum at the bottom.
+    #[props(default)]
+    orientation: Orientation,
+
+    name: Option<&'a str>,
+    disabled: Option<bool>,
+
+    /// Called continuously while the thumb is dragged.
+    on_change: Option<EventHandler<'a, f64>>,
+
+    /// Called once the thumb is released (or moved with the keyboard).
+    ///
+    /// Use it instead of `on_change` for expensive updates.
+    on_commit: Option<EventHandler<'a, f64>>,
+}
+
+/// Selects a value from a range by dragging a thumb along a track, like `GtkScale`.
+///
+/// Drawn like the slider of the [`Theme`](crate::Theme): `GtkScale`, `QSlider`, the WinUI `Slider` or `NSSlider`.
+///
+/// # Example
+/// ```
+/// let brightness = use_state(cx, || 50.0);
+///
+/// render! {
+///     Slider {
+///         aria_label: "Brightness",
+///         value: *brightness.get(),
+///         marks: &[(0.0, Some("Off")), (50.0, None), (100.0, Some("Max"))],
+///         on_change: move |v| brightness.set(v),
+///         on_commit: move |v| save_brightness(v)
+///     }
+/// }
+/// ```
+pub fn Slider<'a>(cx: Scope<'a, SliderProps<'a>>) -> Element<'a> {
+    let SliderProps {
+        value,
+        min,
+        max,
+        step,
+        marks,
+        orientation,
+        name,
+        disabled,
+        on_change,
+        on_commit,
+        oninput,
+        onchange,
+        ..
+    } = cx.props;
+
+    let (layout, aria_orientation) = match orientation {
+        Orientation::Horizontal => (class::slider_horizontal, "horizontal"),
+        Orientation::Vertical => (class::slider_vertical, "vertical"),
+    };
+    let fill = position(*value, *min, *max);
+    let value_text = marks
+        .iter()
+        .find(|(mark, _)| mark == value)
+        .and_then(|(_, label)| *label);
+
+    render_component! {
+        div {
+            $CLASS: "{class::slider} {layout}",
+            style: "--nui-slider-fill: {fill}%",
+            input {
+                class: "{class::slider_input}",
+                r#type: "range",
+                min: "{min}",
+                max: "{max}",
+                step: "{step}",
+                value: "{value}",
+                name: *name,
+                disabled: disabled.map_str(),
+                "aria-orientation": aria_orientation,
+                "aria-valuetext": value_text,
+                oninput: move |e: Event<FormData>| {
+                    if let (Some(on_change), Ok(v)) = (on_change, e.value.parse()) {
+                        on_change.call(v);
+                    }
+                    if let Some(oninput) = oninput {
+                        oninput.call(e);
+                    }
+                },
+                onchange: move |e: Event<FormData>| {
+                    if let (Some(on_commit), Ok(v)) = (on_commit, e.value.parse()) {
+                        on_commit.call(v);
+                    }
+                    if let Some(onchange) = onchange {
+                        onchange.call(e);
+                    }
+                },
+                $GLOBALS,
+            }
+            if !marks.is_empty() {
+                rsx! {
+                    div { class: "{class::slider_marks}", "aria-hidden": "true",
+                        for (mark, label) in marks.iter() {
+                            span {
+                                class: "{class::slider_mark}",
+                                style: "--nui-slider-position: {position(*mark, *min, *max)}%",
+                                *label
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the position of `value` along the track, in percent.
+fn position(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::position;
+
+    #[test]
+    fn positions() {
+        assert_eq!(position(25.0, 0.0, 100.0), 25.0);
+        assert_eq!(position(0.0, -10.0, 10.0), 50.0);
+        // Out of the range, at its ends
+        assert_eq!(position(150.0, 0.0, 100.0), 100.0);
+        assert_eq!(position(-5.0, 0.0, 100.0), 0.0);
+        // Empty or swapped range
+        assert_eq!(position(5.0, 5.0, 5.0), 0.0);
+        assert_eq!(position(5.0, 10.0, 0.0), 0.0);
+    }
+}
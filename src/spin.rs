@@ -0,0 +1,303 @@
+use crate::{
+    class,
+    diagnostics::{misuse, use_first_render},
+    icons::{Minus, Plus},
+    use_localization, Locale, MapStr, ToStr,
+};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct SpinButtonProps<'a> {
+    /// Current value.
+    value: f64,
+
+    /// Minimum value, unbounded by default.
+    ///
+    /// Swapped with `max` if it's greater.
+    #[props(default = f64::NEG_INFINITY)]
+    min: f64,
+
+    /// Maximum value, unbounded by default.
+    #[props(default = f64::INFINITY)]
+    max: f64,
+
+    /// Amount added or subtracted by the buttons, the arrow keys and the mouse wheel.
+    ///
+    /// Page Up and Page Down use ten times the step. Its sign is ignored, and `0.0` disables them.
+    #[props(default = 1.0)]
+    step: f64,
+
+    /// Number of decimal digits shown, values are rounded to them.
+    #[props(default)]
+    digits: usize,
+
+    name: Option<&'a str>,
+    disabled: Option<bool>,
+
+    /// Called with the new value, already clamped and rounded.
+    on_change: Option<EventHandler<'a, f64>>,
+}
+
+/// Numeric entry with buttons to increase and decrease the value, like `GtkSpinButton`.
+///
+/// The value is formatted and parsed with the [`Locale`] of [`InitNui`](crate::InitNui).
+///
+/// The mouse wheel changes the value only while the entry is focused.
+///
+/// # Example
+/// ```
+/// let volume = use_state(cx, || 50.0);
+///
+/// render! {
+///     SpinButton {
+///         aria_label: "Volume",
+///         value: *volume.get(),
+///         min: 0.0,
+///         max: 100.0,
+///         step: 5.0,
+///         on_change: move |v| volume.set(v)
+///     }
+/// }
+/// ```
+pub fn SpinButton<'a>(cx: Scope<'a, SpinButtonProps<'a>>) -> Element<'a> {
+    let SpinButtonProps {
+        value,
+        min,
+        max,
+        step,
+        digits,
+        name,
+        disabled,
+        on_change,
+        onkeydown,
+        onwheel,
+        onchange,
+        onfocus,
+        onblur,
+        ..
+    } = cx.props;
+    let (value, min, max, step, digits) = (*value, *min, *max, *step, *digits);
+
+    // `f64::clamp` panics if `min > max` or if one of them is NaN
+    let ordered = bounds(min, max);
+    if use_first_render(cx) && ordered != (min, max) {
+        misuse!(
+            "SpinButton",
+            "`min` ({min}) must not be greater than `max` ({max}), using {} and {}",
+            ordered.0,
+            ordered.1
+        );
+    }
+    let (min, max) = ordered;
+
+    // Text typed in the entry, until it's applied
+    let edited = use_state(cx, || None::<String>);
+    // The wheel only changes the value of a focused entry, so scrolling the page over it still works
+    let focused = use_state(cx, || false);
+
+    let localization = use_localization(cx);
+    // Entries are not grouped, "1234.5" instead of "1,234.5"
+    let locale = Locale {
+        group_separator: None,
+        ..localization.locale
+    };
+    let text = edited
+        .get()
+        .clone()
+        .unwrap_or_else(|| locale.format_number(value, digits));
+    let (decrease, increase) = (
+        localization.messages.decrease(),
+        localization.messages.increase(),
+    );
+
+    let prevent_keys = match (min.is_finite(), max.is_finite()) {
+        (true, true) => "ArrowUp ArrowDown PageUp PageDown Home End",
+        (true, false) => "ArrowUp ArrowDown PageUp PageDown Home",
+        (false, true) => "ArrowUp ArrowDown PageUp PageDown End",
+        (false, false) => "ArrowUp ArrowDown PageUp PageDown",
+    };
+
+    let set = move |new: f64| {
+        edited.set(None);
+        let new = round(new, digits).clamp(min, max);
+        if let Some(on_change) = on_change {
+            if new != value {
+                on_change.call(new);
+            }
+        }
+    };
+    // Steps from the text being typed if it's valid, so typing "12" and pressing Up gives 13
+    let step_by = move |steps: f64| {
+        let current = edited
+            .get()
+            .as_deref()
+            .and_then(|text| locale.parse_number(text))
+            .unwrap_or(value);
+        set(stepped(current, steps, step, min, max));
+    };
+
+    render_component! {
+        div {
+            $CLASS: "{class::spin}",
+            input {
+                class: "{class::spin_entry}",
+                r#type: "text",
+                inputmode: if digits == 0 { "numeric" } else { "decimal" },
+                role: "spinbutton",
+                name: *name,
+                disabled: disabled.map_str(),
+                value: "{text}",
+                "aria-valuenow": value,
+                "aria-valuemin": min.is_finite().then_some(min),
+                "aria-valuemax": max.is_finite().then_some(max),
+                "data-nui-prevent-keys": prevent_keys,
+                prevent_default: if *focused.get() { "onwheel" } else { "" },
+                oninput: move |e: Event<FormData>| edited.set(Some(e.value.clone())),
+                onkeydown: move |e: KeyboardEvent| {
+                    match e.key() {
+                        Key::ArrowUp => step_by(1.0),
+                        Key::ArrowDown => step_by(-1.0),
+                        Key::PageUp => step_by(10.0),
+                        Key::PageDown => step_by(-10.0),
+                        Key::Home if min.is_finite() => set(min),
+                        Key::End if max.is_finite() => set(max),
+                        _ => {}
+                    }
+                    if let Some(onkeydown) = onkeydown {
+                        onkeydown.call(e);
+                    }
+                },
+                onwheel: move |e: WheelEvent| {
+                    let delta = e.delta().strip_units().y;
+                    if *focused.get() && delta < 0.0 {
+                        step_by(1.0);
+                    } else if *focused.get() && delta > 0.0 {
+                        step_by(-1.0);
+                    }
+                    if let Some(onwheel) = onwheel {
+                        onwheel.call(e);
+                    }
+                },
+                // Typed values are applied on Enter or when the entry loses focus,
+                // invalid ones are replaced by the current value
+                onchange: move |e: Event<FormData>| {
+                    match locale.parse_number(&e.value) {
+                        Some(new) => set(new),
+                        None => edited.set(None),
+                    }
+                    if let Some(onchange) = onchange {
+                        onchange.call(e);
+                    }
+                },
+                onfocus: move |e: FocusEvent| {
+                    focused.set(true);
+                    if let Some(onfocus) = onfocus {
+                        onfocus.call(e);
+                    }
+                },
+                onblur: move |e: FocusEvent| {
+                    focused.set(false);
+                    edited.set(None);
+                    if let Some(onblur) = onblur {
+                        onblur.call(e);
+                    }
+                },
+                $GLOBALS,
+            }
+            button {
+                class: "{class::spin_button}",
+                r#type: "button",
+                tabindex: -1,
+                "aria-label": "{decrease}",
+                disabled: (*disabled == Some(true) || value <= min).to_str(),
+                onclick: move |_| step_by(-1.0),
+                Minus {}
+            }
+            button {
+                class: "{class::spin_button}",
+                r#type: "button",
+                tabindex: -1,
+                "aria-label": "{increase}",
+                disabled: (*disabled == Some(true) || value >= max).to_str(),
+                onclick: move |_| step_by(1.0),
+                Plus {}
+            }
+        }
+    }
+}
+
+/// Returns the bounds in order, NaN ones are unbounded.
+fn bounds(min: f64, max: f64) -> (f64, f64) {
+    let min = if min.is_nan() { f64::NEG_INFINITY } else { min };
+    let max = if max.is_nan() { f64::INFINITY } else { max };
+    (min.min(max), min.max(max))
+}
+
+/// Returns `value` moved by `steps` times `step` and clamped, without moving it if `step` is `0.0` or not finite.
+///
+/// A `value` out of the bounds is clamped first, so stepping from it stays next to the bound.
+fn stepped(value: f64, steps: f64, step: f64, min: f64, max: f64) -> f64 {
+    let value = value.clamp(min, max);
+    if !step.is_finite() {
+        return value;
+    }
+    (value + steps * step.abs()).clamp(min, max)
+}
+
+/// Rounds `value` to `digits` decimal digits.
+fn round(value: f64, digits: usize) -> f64 {
+    // More digits than `f64` can represent would make `factor` infinite
+    let factor = 10f64.powi(digits.min(f64::DIGITS as usize) as i32);
+    let rounded = (value * factor).round() / factor;
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bounds, round, stepped};
+
+    #[test]
+    fn swapped_bounds() {
+        assert_eq!(bounds(0.0, 10.0), (0.0, 10.0));
+        assert_eq!(bounds(10.0, 0.0), (0.0, 10.0));
+        assert_eq!(bounds(f64::NAN, 5.0), (f64::NEG_INFINITY, 5.0));
+        assert_eq!(bounds(5.0, f64::NAN), (5.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(stepped(12.0, 1.0, 1.0, 0.0, 100.0), 13.0);
+        assert_eq!(stepped(12.0, -10.0, 1.0, 0.0, 100.0), 2.0);
+        assert_eq!(stepped(99.5, 1.0, 1.0, 0.0, 100.0), 100.0);
+        // Out of bounds, clamped before stepping
+        assert_eq!(stepped(150.0, -1.0, 1.0, 0.0, 100.0), 99.0);
+        // The sign of the step is ignored
+        assert_eq!(stepped(5.0, 1.0, -2.0, 0.0, 100.0), 7.0);
+    }
+
+    #[test]
+    fn zero_step() {
+        assert_eq!(stepped(5.0, 1.0, 0.0, 0.0, 10.0), 5.0);
+        assert_eq!(stepped(5.0, -10.0, 0.0, 0.0, 10.0), 5.0);
+        assert_eq!(stepped(5.0, 1.0, f64::NAN, 0.0, 10.0), 5.0);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(round(0.1 + 0.2, 1), 0.3);
+        assert_eq!(round(0.1 + 0.2, 2), 0.3);
+        assert_eq!(round(1.005 * 1000.0, 0), 1005.0);
+        assert_eq!(round(2.5, 0), 3.0);
+        assert_eq!(round(-2.5, 0), -3.0);
+        assert_eq!(round(1.23456, 3), 1.235);
+        // Not rounded beyond the precision of `f64`
+        assert_eq!(round(1.5, 400), 1.5);
+        assert_eq!(round(f64::MAX, 10), f64::MAX);
+    }
+}
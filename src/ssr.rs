@@ -0,0 +1,62 @@
+//! Server-side rendering of NUI apps with [dioxus-ssr](https://crates.io/crates/dioxus-ssr).
+//!
+//! The theme's stylesheet is not rendered inline, it's returned separately so it can be placed once in `<head>` or linked.
+//!
+//! Each call renders a new `VirtualDom`, so no state is shared between requests.
+//!
+//! # Example
+//! ```
+//! use dioxus_nui::ssr;
+//!
+//! // Complete document, with the stylesheet in <head>
+//! let html = ssr::render(App).to_document();
+//!
+//! // Body and stylesheet separately
+//! let rendered = ssr::render(App);
+//! std::fs::write("nui.css", rendered.stylesheet().unwrap_or_default())?;
+//! ```
+
+use crate::{init::StylesheetSink, Theme};
+use dioxus::prelude::*;
+
+/// Result of rendering an app on the server.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Rendered {
+    /// HTML of the app, without the stylesheet.
+    pub body: String,
+    /// Theme used by the app, `None` if no NUI component was rendered.
+    pub theme: Option<Theme>,
+}
+
+impl Rendered {
+    /// Returns the stylesheet of the theme used by the app.
+    pub fn stylesheet(&self) -> Option<&'static str> {
+        self.theme.map(Theme::stylesheet)
+    }
+
+    /// Returns a complete HTML document, with the stylesheet placed in `<head>`.
+    pub fn to_document(&self) -> String {
+        let style = self.stylesheet().unwrap_or_default();
+        format!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><style>{style}</style></head><body>{}</body></html>",
+            self.body
+        )
+    }
+}
+
+/// Renders `app` to HTML.
+pub fn render(app: Component) -> Rendered {
+    render_with_props(app, ())
+}
+
+/// Renders `app` with `props` to HTML.
+pub fn render_with_props<P: 'static>(app: Component<P>, props: P) -> Rendered {
+    let sink = StylesheetSink::default();
+    let mut vdom = VirtualDom::new_with_props(app, props).with_root_context(sink.clone());
+    let _ = vdom.rebuild();
+
+    Rendered {
+        body: dioxus_ssr::render(&vdom),
+        theme: sink.0.get(),
+    }
+}
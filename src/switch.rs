@@ -0,0 +1,66 @@
+use crate::{class, MapStr, ToStr};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component]
+#[derive(Props)]
+pub struct SwitchProps<'a> {
+    /// Whether the switch is on.
+    #[props(default)]
+    active: bool,
+    disabled: Option<bool>,
+
+    /// Called with the new state when the switch is toggled.
+    on_toggle: Option<EventHandler<'a, bool>>,
+}
+
+/// On/off switch, for settings that apply immediately.
+///
+/// Rendered as a `<button>` with the `switch` role, so it's toggled with a click, Space or Enter.
+///
+/// Set `aria_label` (or `aria_labelledby`) to name it, as it has no text.
+///
+/// Drawn like the switch of the [`Theme`](crate::Theme): `GtkSwitch`, the Breeze switch, the WinUI `ToggleSwitch` or `NSSwitch`.
+///
+/// # Example
+/// ```
+/// let dark = use_state(cx, || true);
+///
+/// render! {
+///     ListItem {
+///         title: "Dark mode",
+///         suffix: render! {
+///             Switch { aria_label: "Dark mode", active: *dark.get(), on_toggle: move |a| dark.set(a) }
+///         }
+///     }
+/// }
+/// ```
+pub fn Switch<'a>(cx: Scope<'a, SwitchProps<'a>>) -> Element<'a> {
+    let SwitchProps {
+        active,
+        disabled,
+        on_toggle,
+        onclick,
+        ..
+    } = cx.props;
+
+    render_component! {
+        button {
+            $CLASS: "{class::switch}",
+            r#type: "button",
+            role: "switch",
+            "aria-checked": active.to_str(),
+            disabled: disabled.map_str(),
+            onclick: move |e| {
+                if let Some(on_toggle) = on_toggle {
+                    on_toggle.call(!active);
+                }
+                if let Some(onclick) = onclick {
+                    onclick.call(e);
+                }
+            },
+            $GLOBALS,
+            span { class: "{class::switch_thumb}" }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    class::{self, Classes},
+    use_localization, MapStr, ToStr,
+};
+use dioxus::prelude::*;
+use dioxus_nui_macros::{nui_component, render_component};
+
+#[nui_component(form)]
+#[derive(Props)]
+pub struct TextAreaProps<'a> {
+    /// Text of the area, the typed one is kept if not specified.
+    value: Option<&'a str>,
+    name: Option<&'a str>,
+    label: Option<&'a str>,
+
+    /// Visible lines of text, 3 by default.
+    #[props(default = 3)]
+    rows: usize,
+
+    /// Called with the text every time it changes.
+    on_change: Option<EventHandler<'a, String>>,
+
+    /// How long lines are wrapped, `TextWrap::Soft` by default.
+    #[props(default)]
+    wrap: TextWrap,
+
+    /// Directions in which the user can resize the area, `Resize::Vertical` by default.
+    #[props(default)]
+    resize: Resize,
+
+    /// Maximum number of characters, shown in the counter.
+    max_length: Option<usize>,
+
+    /// Grows the area with its content, `rows` being the minimum.
+    ///
+    /// Only the line breaks are counted, lines wrapped by the width are handled by the CSS `field-sizing`
+    /// where browsers support it.
+    #[props(default)]
+    auto_grow: bool,
+
+    /// Shows the number of characters below the area, `Messages::characters`.
+    #[props(default)]
+    counter: bool,
+
+    /// Uses a monospace font, for code-like content.
+    #[props(default)]
+    monospace: bool,
+}
+
+/// Multi-line text field, with the same label and styling conventions as [`Input`](crate::Input).
+///
+/// # Example
+/// ```
+/// let bio = use_state(cx, String::new);
+///
+/// render! {
+///     TextArea {
+///         label: "Bio",
+///         value: bio.as_str(),
+///         max_length: 280,
+///         counter: true,
+///         auto_grow: true,
+///         on_change: move |v| bio.set(v)
+///     }
+/// }
+/// ```
+pub fn TextArea<'a>(cx: Scope<'a, TextAreaProps<'a>>) -> Element<'a> {
+    let TextAreaProps {
+        value,
+        name,
+        label,
+        rows,
+        on_change,
+        wrap,
+        resize,
+        max_length,
+        auto_grow,
+        counter,
+        monospace,
+        oninput,
+        ..
+    } = cx.props;
+
+    // Needed by the counter and `auto_grow` when the value is not controlled
+    let typed = use_state(cx, || value.unwrap_or_default().to_string());
+    let text = value.unwrap_or(typed.as_str());
+
+    let rows = if *auto_grow {
+        grown_rows(text, *rows)
+    } else {
+        *rows
+    };
+    let modifiers = Classes::new()
+        .add(resize.to_class())
+        .when(*auto_grow, class::textarea_auto_grow)
+        .when(*monospace, class::textarea_monospace);
+    let messages = use_localization(cx).messages;
+    let count = text.chars().count();
+    let characters = counter.then(|| messages.characters(count, *max_length));
+
+    render_component! {
+        label {
+            class: "{class::label}",
+            *label,
+            textarea {
+                $CLASS: "{class::textarea} {modifiers}",
+                name: *name,
+                rows: rows as i64,
+                wrap: wrap.to_str(),
+                maxlength: max_length.map(|max| max as i64),
+                value: text,
+                oninput: move |e: Event<FormData>| {
+                    typed.set(e.value.clone());
+                    if let Some(on_change) = on_change {
+                        on_change.call(e.value.clone());
+                    }
+                    if let Some(oninput) = oninput {
+                        oninput.call(e);
+                    }
+                },
+                $GLOBALS,
+            }
+        }
+        characters.map(|characters| rsx! {
+            span {
+                class: "{class::textarea_counter}",
+                "aria-live": "polite",
+                "data-full": max_length.map(|max| count >= max).map_str(),
+                "{characters}"
+            }
+        })
+    }
+}
+
+/// How the lines of a [`TextArea`] are wrapped, value of the `wrap` attribute.
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+pub enum TextWrap {
+    /// Long lines are wrapped when displayed, but not in the value.
+    #[default]
+    Soft,
+    /// Long lines are wrapped, also in the value submitted with a form.
+    Hard,
+    /// Long lines are not wrapped, the area scrolls horizontally.
+    Off,
+}
+
+impl ToStr<'static> for TextWrap {
+    fn to_str(&self) -> &'static str {
+        match self {
+            TextWrap::Soft => "soft",
+            TextWrap::Hard => "hard",
+            TextWrap::Off => "off",
+        }
+    }
+}
+
+/// Directions in which the user can resize a [`TextArea`].
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+pub enum Resize {
+    None,
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl Resize {
+    fn to_class(self) -> &'static str {
+        match self {
+            Resize::None => class::textarea_resize_none,
+            Resize::Vertical => class::textarea_resize_vertical,
+            Resize::Horizontal => class::textarea_resize_horizontal,
+            Resize::Both => class::textarea_resize_both,
+        }
+    }
+}
+
+/// Returns the rows needed to show every line of `text`, at least `rows`.
+fn grown_rows(text: &str, rows: usize) -> usize {
+    // Unlike `lines()`, counts the empty line after a trailing line break, where the caret goes
+    rows.max(text.split('\n').count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::grown_rows;
+
+    #[test]
+    fn grows() {
+        assert_eq!(grown_rows("", 3), 3);
+        assert_eq!(grown_rows("one\ntwo", 1), 2);
+        assert_eq!(grown_rows("one\ntwo\n", 1), 3);
+        assert_eq!(grown_rows("one\n\n\nfour", 2), 4);
+        // Never shrinks below `rows`
+        assert_eq!(grown_rows("one\ntwo", 5), 5);
+    }
+}
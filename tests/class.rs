@@ -0,0 +1,40 @@
+use dioxus_nui::class::{self, Classes};
+
+#[test]
+fn display() {
+    assert_eq!(Classes::new().to_string(), "");
+    assert_eq!(
+        Classes::from(class::h1).add(class::accent).to_string(),
+        "nui-h1 nui-accent"
+    );
+    assert_eq!(
+        Classes::from("  spaced   classes ").to_string(),
+        "spaced classes"
+    );
+}
+
+#[test]
+fn deduplicates() {
+    let classes = Classes::from("a b").add("b c").add("a");
+    assert_eq!(classes.to_string(), "a b c");
+    assert_eq!(classes, ["a", "b", "c"].into_iter().collect());
+}
+
+#[test]
+fn conditional() {
+    let classes = Classes::from(class::btn)
+        .when(true, class::accent)
+        .when(false, class::accent_hover);
+    assert!(classes.contains(class::accent));
+    assert!(!classes.contains(class::accent_hover));
+}
+
+#[test]
+fn merge() {
+    let user = Classes::from("custom nui-h1");
+    assert_eq!(
+        Classes::from(class::h1).merge(&user).to_string(),
+        "nui-h1 custom"
+    );
+    assert_eq!(Classes::from(class::h1).merge(None).to_string(), "nui-h1");
+}
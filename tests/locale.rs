@@ -0,0 +1,72 @@
+use dioxus_nui::{Locale, Localization, Messages};
+use std::rc::Rc;
+
+#[test]
+fn format_number() {
+    assert_eq!(Locale::EN_US.format_number(1234567.891, 2), "1,234,567.89");
+    assert_eq!(Locale::DE.format_number(1234.5, 2), "1.234,50");
+    assert_eq!(Locale::ISO.format_number(1234.5, 1), "1234.5");
+    assert_eq!(Locale::EN_US.format_number(-999.0, 0), "-999");
+    assert_eq!(Locale::EN_US.format_number(-0.001, 2), "0.00");
+}
+
+#[test]
+fn parse_number() {
+    assert_eq!(Locale::DE.parse_number("1.234,5"), Some(1234.5));
+    // Groups are of three digits
+    assert_eq!(Locale::DE.parse_number("2.5"), None);
+    assert_eq!(Locale::DE.parse_number("2.500"), Some(2500.0));
+    assert_eq!(Locale::EN_US.parse_number("1,23"), None);
+    assert_eq!(Locale::FR.parse_number("1 234,5"), Some(1234.5));
+    assert_eq!(Locale::FR.parse_number("2.5"), Some(2.5));
+    assert_eq!(Locale::EN_US.parse_number(" -1,000.25 "), Some(-1000.25));
+    assert_eq!(Locale::EN_US.parse_number("abc"), None);
+}
+
+#[test]
+fn format_date() {
+    assert_eq!(Locale::EN_US.format_date(2023, 7, 4), "07/04/2023");
+    assert_eq!(Locale::DE.format_date(2023, 7, 4), "04.07.2023");
+    assert_eq!(Locale::ISO.format_date(2023, 7, 4), "2023-07-04");
+}
+
+#[test]
+fn from_tag() {
+    assert_eq!(Locale::from_tag("en_GB.UTF-8"), Some(Locale::EN_GB));
+    assert_eq!(Locale::from_tag("de-AT"), Some(Locale::DE));
+    assert_eq!(Locale::from_tag("xx"), None);
+}
+
+#[test]
+fn format_file_size() {
+    let localization = Localization::default();
+    assert_eq!(localization.format_file_size(999), "999 B");
+    assert_eq!(localization.format_file_size(1000), "1 kB");
+    assert_eq!(localization.format_file_size(999_499), "999 kB");
+    assert_eq!(localization.format_file_size(999_999), "1 MB");
+    assert_eq!(localization.format_file_size(1_500_000), "1.5 MB");
+    assert_eq!(localization.format_file_size(12_345_678_901), "12 GB");
+}
+
+#[test]
+fn messages() {
+    struct Spanish;
+
+    impl Messages for Spanish {
+        fn search(&self) -> &str {
+            "Buscar…"
+        }
+    }
+
+    let localization = Localization {
+        locale: Locale::ES,
+        messages: Rc::new(Spanish),
+    };
+    assert_eq!(localization.messages.search(), "Buscar…");
+    // Not translated, keeps the English default
+    assert_eq!(localization.messages.select(), "Select…");
+    assert_eq!(localization.messages.month(12), "December");
+    assert_eq!(localization.messages.month(0), "");
+    assert_eq!(localization.messages.characters(3, Some(10)), "3/10");
+    assert_eq!(localization.format_file_size(2_500), "2,5 kB");
+}
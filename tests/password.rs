@@ -0,0 +1,29 @@
+use dioxus_nui::PasswordStrength;
+
+#[test]
+fn estimate() {
+    assert_eq!(PasswordStrength::estimate(""), PasswordStrength::Weak);
+    assert_eq!(
+        PasswordStrength::estimate("password"),
+        PasswordStrength::Weak
+    );
+    assert_eq!(
+        PasswordStrength::estimate("Passw0rd!"),
+        PasswordStrength::Fair
+    );
+    assert_eq!(
+        PasswordStrength::estimate("correct horse battery"),
+        PasswordStrength::Good
+    );
+    assert_eq!(
+        PasswordStrength::estimate("Correct-Horse-Battery-9"),
+        PasswordStrength::Strong
+    );
+}
+
+#[test]
+fn score() {
+    assert_eq!(PasswordStrength::Weak.score(), 1);
+    assert_eq!(PasswordStrength::Strong.score(), 4);
+    assert!(PasswordStrength::Fair < PasswordStrength::Good);
+}
@@ -0,0 +1,608 @@
+#![allow(non_snake_case)]
+
+//! Renders every component headlessly with dioxus-ssr under each `Theme`.
+//!
+//! The markup is compared against the snapshots in `tests/snapshots`, once per component since the theme only changes the stylesheet.
+//! Missing snapshots are written on the first run and must be committed, in CI (when `CI` is set) they fail the test instead.
+//! Set `NUI_UPDATE_SNAPSHOTS=1` to overwrite them after an intended change.
+//!
+//! Run with `cargo test --features ssr`.
+
+use dioxus::prelude::*;
+use dioxus_nui::{
+    ssr, Accordion, AccordionSection, AutocompleteEntry, Button, ButtonStyle, Checkbox, Dropdown,
+    ExpanderRow, InitNui, Input, InputType, List, ListItem, Orientation, PasswordEntry,
+    PasswordStrength, RadioGroup, Resize, RovingGroup, Slider, SpinButton, Suggestions, Switch,
+    TextArea, TextWrap, Theme, H1, H2, H3, H4,
+};
+use std::path::PathBuf;
+
+const THEMES: [Theme; 5] = [
+    Theme::Adwaita,
+    Theme::Qt,
+    Theme::Windows10,
+    Theme::Windows11,
+    Theme::Macos,
+];
+
+const INPUT_TYPES: [InputType; 22] = [
+    InputType::Button,
+    InputType::Checkbox,
+    InputType::Color,
+    InputType::Date,
+    InputType::DatetimeLocal,
+    InputType::Email,
+    InputType::File,
+    InputType::Hidden,
+    InputType::Image,
+    InputType::Month,
+    InputType::Number,
+    InputType::Password,
+    InputType::Radio,
+    InputType::Range,
+    InputType::Reset,
+    InputType::Search,
+    InputType::Submit,
+    InputType::Tel,
+    InputType::Text,
+    InputType::Time,
+    InputType::Url,
+    InputType::Week,
+];
+
+/// Compares `html` with the snapshot `name`.
+///
+/// A missing snapshot is written, unless running in CI. `NUI_UPDATE_SNAPSHOTS` overwrites it.
+fn assert_snapshot(name: &str, html: &str) {
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "snapshots",
+        &format!("{name}.html"),
+    ]
+    .iter()
+    .collect();
+
+    let update = std::env::var_os("NUI_UPDATE_SNAPSHOTS").is_some();
+    if update || (!path.exists() && std::env::var_os("CI").is_none()) {
+        std::fs::write(&path, html).unwrap();
+        eprintln!("wrote snapshot `{name}` to {}, commit it", path.display());
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "snapshot `{name}` is missing, run the tests locally to write {}",
+            path.display()
+        )
+    });
+    assert_eq!(
+        expected,
+        html,
+        "snapshot `{name}` does not match, see {}",
+        path.display()
+    );
+}
+
+/// Renders `app` under every theme and returns the body, to be snapshotted once.
+///
+/// The theme only changes the stylesheet, so the body must be the same for all of them.
+fn render_themes<P: 'static>(app: Component<P>, props: impl Fn(Theme) -> P) -> String {
+    let mut body = None;
+    for theme in THEMES {
+        let rendered = ssr::render_with_props(app, props(theme));
+        assert_eq!(rendered.theme, Some(theme));
+        match &body {
+            None => body = Some(rendered.body),
+            Some(body) => assert_eq!(body, &rendered.body, "markup changed with {theme:?}"),
+        }
+    }
+    body.unwrap()
+}
+
+#[inline_props]
+fn Buttons(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        Button { "Regular" }
+        Button { button_style: ButtonStyle::Compact, "Compact" }
+        Button { button_style: ButtonStyle::Pill, "Pill" }
+        Button { button_style: ButtonStyle::Circular, "C" }
+        Button { disabled: true, "Disabled" }
+        Button { pressed: true, "Pressed" }
+        Button { accent: true, class: "custom", "Accent" }
+    }
+}
+
+#[inline_props]
+fn Inputs(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        for input_type in INPUT_TYPES {
+            Input { input_type: input_type, label: "Label", on_change: |_| {} }
+        }
+    }
+}
+
+#[inline_props]
+fn Lists(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        List {
+            ListItem { title: "Title", subtitle: "Subtitle" }
+            ListItem { title: "Prefix", prefix: render! { Button { "Prefix" } } }
+            ListItem { title: "Suffix", suffix: render! { Button { "Suffix" } } }
+            ListItem { accent: true, class: "custom", title: "Accent" }
+        }
+    }
+}
+
+#[inline_props]
+fn Headers(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        H1 { "Title 1" }
+        H2 { "Title 2" }
+        H3 { "Title 3" }
+        H4 { "Title 4" }
+    }
+}
+
+#[test]
+fn init_nui() {
+    for theme in THEMES {
+        let rendered = ssr::render_with_props(Headers, HeadersProps { theme });
+
+        // The stylesheet goes to <head>, not inline
+        assert_eq!(rendered.theme, Some(theme));
+        assert!(!rendered.body.contains("<style"));
+        assert!(rendered.to_document().contains(&format!(
+            "<head><meta charset=\"utf-8\"><style>{}",
+            theme.stylesheet()
+        )));
+    }
+}
+
+#[test]
+fn auto_init() {
+    fn App(cx: Scope) -> Element {
+        render! { Button { "Button" } }
+    }
+
+    assert_eq!(ssr::render(App).theme, Some(Theme::default()));
+}
+
+#[test]
+fn buttons() {
+    let body = render_themes(Buttons, |theme| ButtonsProps { theme });
+    assert_snapshot("buttons", &body);
+}
+
+#[test]
+fn inputs() {
+    let body = render_themes(Inputs, |theme| InputsProps { theme });
+    for input_type in INPUT_TYPES {
+        assert!(body.contains(&format!("type=\"{input_type}\"")));
+    }
+    assert_snapshot("inputs", &body);
+}
+
+#[test]
+fn lists() {
+    let body = render_themes(Lists, |theme| ListsProps { theme });
+    assert!(body.contains("role=\"list\""));
+    assert!(body.contains("role=\"listitem\""));
+    assert_snapshot("lists", &body);
+}
+
+#[test]
+fn headers() {
+    let body = render_themes(Headers, |theme| HeadersProps { theme });
+    assert_snapshot("headers", &body);
+}
+
+#[test]
+fn class_merging() {
+    let rendered = ssr::render_with_props(
+        Buttons,
+        ButtonsProps {
+            theme: Theme::Adwaita,
+        },
+    );
+    // `$CLASS`, then `accent`, then `class`
+    assert!(rendered
+        .body
+        .contains("class=\"nui-btn nui-btn--regular nui-accent custom\""));
+    // No stray spaces without `accent` or `class`
+    assert!(rendered.body.contains("class=\"nui-btn nui-btn--regular\""));
+
+    let rendered = ssr::render_with_props(
+        Lists,
+        ListsProps {
+            theme: Theme::Adwaita,
+        },
+    );
+    assert!(rendered
+        .body
+        .contains("class=\"nui-list__item nui-accent custom\""));
+}
+
+#[test]
+fn bound_inputs() {
+    fn App(cx: Scope) -> Element {
+        let name = use_state(cx, || "NUI".to_string());
+        let age = use_state(cx, || 42u8);
+        let subscribed = use_state(cx, || true);
+
+        render! {
+            Input { bind: name }
+            Input { bind: age }
+            Input { bind: subscribed }
+            // The explicit type takes precedence
+            Input { bind: age, input_type: InputType::Range }
+        }
+    }
+
+    let rendered = ssr::render(App);
+    assert!(rendered.body.contains("type=\"text\" value=\"NUI\""));
+    assert!(rendered.body.contains("type=\"number\" value=\"42\""));
+    assert!(rendered
+        .body
+        .contains("type=\"checkbox\" value=\"true\" checked=\"true\""));
+    assert!(rendered.body.contains("type=\"range\" value=\"42\""));
+    assert_snapshot("input_bound", &rendered.body);
+}
+
+#[test]
+fn roving_tabindex() {
+    fn App(cx: Scope) -> Element {
+        render! {
+            List {
+                ListItem { title: "Static" }
+                ListItem { title: "First", onclick: |_| {} }
+                ListItem { title: "Second", onclick: |_| {} }
+            }
+            RovingGroup {
+                Button { disabled: true, "Disabled" }
+                Button { "Bold" }
+                Button { "Italic" }
+            }
+            // Outside a group, keeps the default
+            Button { "Alone" }
+        }
+    }
+
+    let body = ssr::render(App).body;
+    // Only the first enabled item of each group is a tab stop
+    assert_eq!(body.matches("tabindex=0").count(), 2);
+    assert_eq!(body.matches("tabindex=-1").count(), 2);
+    assert_snapshot("roving_tabindex", &body);
+}
+
+#[inline_props]
+fn Checkboxes(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        Checkbox { label: "Unchecked" }
+        Checkbox { label: "Checked", checked: true }
+        Checkbox { label: "Indeterminate", checked: true, indeterminate: true }
+        Checkbox { label: "Disabled", disabled: true }
+    }
+}
+
+#[test]
+fn checkboxes() {
+    let body = render_themes(Checkboxes, |theme| CheckboxesProps { theme });
+    assert_eq!(body.matches("type=\"checkbox\"").count(), 4);
+    assert_eq!(body.matches("nui-checkbox--indeterminate").count(), 1);
+    // The property is set once mounted, the attribute is not valid on a native checkbox
+    assert!(!body.contains("aria-checked"));
+}
+
+#[inline_props]
+fn Switches(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        Switch { aria_label: "Off" }
+        Switch { aria_label: "On", active: true }
+        Switch { aria_label: "Disabled", disabled: true }
+    }
+}
+
+#[test]
+fn switches() {
+    let body = render_themes(Switches, |theme| SwitchesProps { theme });
+    assert_eq!(body.matches("role=\"switch\"").count(), 3);
+    assert_eq!(body.matches("aria-checked=\"true\"").count(), 1);
+    assert_eq!(body.matches("aria-checked=\"false\"").count(), 2);
+}
+
+#[inline_props]
+fn RadioGroups(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        RadioGroup {
+            aria_label: "Size",
+            options: &[(1, "Small"), (2, "Medium"), (3, "Large")],
+            selected: 2
+        }
+        RadioGroup {
+            aria_label: "Horizontal",
+            orientation: Orientation::Horizontal,
+            name: "horizontal",
+            disabled: true,
+            options: &[("a", "A"), ("b", "B")],
+        }
+    }
+}
+
+#[test]
+fn radio_groups() {
+    let body = render_themes(RadioGroups, |theme| RadioGroupsProps { theme });
+    assert_eq!(body.matches("role=\"radiogroup\"").count(), 2);
+    assert_eq!(body.matches("checked=\"true\"").count(), 1);
+    assert_eq!(body.matches("name=\"horizontal\"").count(), 2);
+}
+
+#[inline_props]
+fn Expanders(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        List {
+            ExpanderRow {
+                title: "Collapsed",
+                subtitle: "Subtitle",
+                ListItem { title: "Nested" }
+            }
+            ExpanderRow {
+                title: "Expanded",
+                default_expanded: true,
+                ListItem { title: "Nested" }
+            }
+            ExpanderRow {
+                title: "Controlled",
+                expanded: false,
+                default_expanded: true,
+                ListItem { title: "Nested" }
+            }
+        }
+        Accordion {
+            AccordionSection { title: "First", default_expanded: true, "First content" }
+            // Only the first one is open without `multiple`
+            AccordionSection { title: "Second", default_expanded: true, "Second content" }
+        }
+        Accordion {
+            multiple: true,
+            AccordionSection { title: "First", default_expanded: true, "First content" }
+            AccordionSection { title: "Second", default_expanded: true, "Second content" }
+        }
+    }
+}
+
+#[test]
+fn expanders() {
+    let body = render_themes(Expanders, |theme| ExpandersProps { theme });
+    // The expanded row, the first section of the first accordion and both of the second
+    assert_eq!(body.matches("aria-expanded=\"true\"").count(), 4);
+    assert_eq!(body.matches("aria-expanded=\"false\"").count(), 3);
+}
+
+#[test]
+fn expander_rows_roving() {
+    fn App(cx: Scope) -> Element {
+        render! {
+            List {
+                ExpanderRow {
+                    title: "Collapsed",
+                    ListItem { title: "Hidden", onclick: |_| {} }
+                }
+                ExpanderRow {
+                    title: "Expanded",
+                    default_expanded: true,
+                    ListItem { title: "Shown", onclick: |_| {} }
+                }
+            }
+        }
+    }
+
+    let body = ssr::render(App).body;
+    // Both headers and the shown row are in the `List`'s group, the hidden row is skipped
+    assert_eq!(body.matches("tabindex=0").count(), 1);
+    assert_eq!(body.matches("tabindex=-1").count(), 2);
+}
+
+#[inline_props]
+fn SpinButtons(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        SpinButton { aria_label: "Unbounded", value: 1234.5, digits: 2 }
+        SpinButton { aria_label: "At minimum", value: 0.0, min: 0.0, max: 10.0 }
+        SpinButton { aria_label: "Disabled", value: 5.0, disabled: true }
+        SpinButton { aria_label: "Swapped", value: 5.0, min: 10.0, max: 0.0 }
+    }
+}
+
+#[test]
+fn spin_buttons() {
+    let body = render_themes(SpinButtons, |theme| SpinButtonsProps { theme });
+    assert_eq!(body.matches("role=\"spinbutton\"").count(), 4);
+    // Not grouped, rounded to `digits`
+    assert!(body.contains("value=\"1234.50\""));
+    // Also for the swapped bounds
+    assert_eq!(body.matches("aria-valuemin=0 aria-valuemax=10").count(), 2);
+    assert!(body.contains("data-nui-prevent-keys=\"ArrowUp ArrowDown PageUp PageDown Home End\""));
+}
+
+#[inline_props]
+fn Sliders(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        Slider { aria_label: "Plain", value: 25.0 }
+        Slider {
+            aria_label: "Marks",
+            value: 5.0,
+            min: 0.0,
+            max: 10.0,
+            marks: &[(0.0, Some("Min")), (5.0, Some("Middle")), (10.0, None)]
+        }
+        Slider { aria_label: "Vertical", value: 50.0, orientation: Orientation::Vertical, disabled: true }
+    }
+}
+
+#[test]
+fn sliders() {
+    let body = render_themes(Sliders, |theme| SlidersProps { theme });
+    assert_eq!(body.matches("type=\"range\"").count(), 3);
+    assert!(body.contains("--nui-slider-fill: 25%"));
+    // The label of the mark at the value
+    assert!(body.contains("aria-valuetext=\"Middle\""));
+    assert!(body.contains("aria-orientation=\"vertical\""));
+    assert_eq!(body.matches("class=\"nui-slider__mark\"").count(), 3);
+}
+
+#[inline_props]
+fn Dropdowns(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        Dropdown {
+            aria_label: "Language",
+            options: &[(1, "Rust"), (2, "Python"), (3, "Go")],
+            selected: 2
+        }
+        Dropdown {
+            aria_label: "Placeholder",
+            options: &[("a", "A"), ("b", "B")],
+            searchable: true
+        }
+        Dropdown {
+            aria_label: "Disabled",
+            options: &[((), "Only")],
+            placeholder: "Nothing",
+            disabled: true
+        }
+    }
+}
+
+#[test]
+fn dropdowns() {
+    let body = render_themes(Dropdowns, |theme| DropdownsProps { theme });
+    assert_eq!(body.matches("role=\"combobox\"").count(), 3);
+    // Closed until clicked
+    assert_eq!(body.matches("aria-expanded=\"false\"").count(), 3);
+    assert!(!body.contains("role=\"listbox\""));
+    assert!(body.contains(">Python</span>"));
+    // `Messages::select` by default
+    assert!(body.contains(">Select…</span>"));
+    assert!(body.contains(">Nothing</span>"));
+    // Handled by the keydown listener only, not by the native click
+    assert_eq!(
+        body.matches("data-nui-prevent-keys=\"ArrowUp ArrowDown Enter Space\"")
+            .count(),
+        3
+    );
+    assert!(body.contains("class=\"nui-dropdown__label nui-dropdown__label--placeholder\""));
+}
+
+#[inline_props]
+fn AutocompleteEntries(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        AutocompleteEntry {
+            label: "Fruit",
+            name: "fruit",
+            id: "fruit-entry",
+            suggestions: &["Apple", "Banana", "Cherry"]
+        }
+        AutocompleteEntry {
+            label: "User",
+            value: "ad",
+            suggestions: Suggestions::from_async(|text| async move { vec![format!("{text}min")] })
+        }
+    }
+}
+
+#[test]
+fn autocomplete_entries() {
+    let body = render_themes(AutocompleteEntries, |theme| AutocompleteEntriesProps {
+        theme,
+    });
+    assert_eq!(body.matches("role=\"combobox\"").count(), 2);
+    assert!(body.contains("value=\"ad\""));
+    // Hidden until something is typed
+    assert!(!body.contains("role=\"listbox\""));
+    // Rendered by `Input`, with the global attributes of the entry
+    assert_eq!(body.matches("class=\"nui-input--text\"").count(), 2);
+    assert!(body.contains("id=\"fruit-entry\""));
+    assert_eq!(body.matches("name=\"fruit\"").count(), 1);
+    assert_eq!(body.matches("aria-autocomplete=\"list\"").count(), 2);
+    assert_eq!(body.matches("autocomplete=\"off\"").count(), 2);
+    // Only busy while a `Source` is asked
+    assert_eq!(body.matches("aria-busy=\"false\"").count(), 2);
+}
+
+#[inline_props]
+fn TextAreas(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        TextArea { label: "Plain" }
+        TextArea {
+            label: "Counter",
+            value: "Hello",
+            max_length: 10,
+            counter: true
+        }
+        TextArea {
+            label: "Code",
+            value: "fn main() {\n    println!();\n}\n\n",
+            rows: 2,
+            wrap: TextWrap::Off,
+            resize: Resize::None,
+            auto_grow: true,
+            monospace: true
+        }
+    }
+}
+
+#[test]
+fn text_areas() {
+    let body = render_themes(TextAreas, |theme| TextAreasProps { theme });
+    assert_eq!(body.matches("<textarea").count(), 3);
+    // `Messages::characters`
+    assert!(body.contains(">5/10</span>"));
+    assert!(body.contains("maxlength=10"));
+    // Grown to the lines of the value, including the empty last one
+    assert!(body.contains("rows=5"));
+    assert!(body.contains("wrap=\"off\""));
+    assert!(body.contains("class=\"nui-textarea nui-textarea--resize-none nui-textarea--auto-grow nui-textarea--monospace\""));
+}
+
+#[inline_props]
+fn PasswordEntries(cx: Scope, theme: Theme) -> Element {
+    render! {
+        InitNui { theme: *theme }
+        PasswordEntry { label: "Password" }
+        PasswordEntry {
+            label: "New password",
+            value: "correct horse battery",
+            strength: &PasswordStrength::estimate
+        }
+    }
+}
+
+#[test]
+fn password_entries() {
+    let body = render_themes(PasswordEntries, |theme| PasswordEntriesProps { theme });
+    assert_eq!(body.matches("type=\"password\"").count(), 2);
+    // `Messages::show_password`
+    assert_eq!(body.matches("aria-label=\"Show password\"").count(), 2);
+    // Only with `strength` and a password
+    assert_eq!(body.matches("<meter").count(), 1);
+    assert!(body.contains("data-strength=\"good\""));
+    assert!(body.contains("autocomplete=\"current-password\""));
+    assert!(body.contains("autocomplete=\"new-password\""));
+    // The peek button is a toggle, its label doesn't change
+    assert_eq!(body.matches("aria-pressed=\"false\"").count(), 2);
+    assert!(!body.contains("Hide password"));
+    // Only shown once Caps Lock is detected
+    assert!(!body.contains("nui-password__caps-lock"));
+}